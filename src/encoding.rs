@@ -1,4 +1,4 @@
-use crate::types::{Register, CRegister};
+use crate::types::{Register, CRegister, FRegister, VRegister, AmoOrdering, Csr, VMask, Lmul, RegisterList};
 
 
 #[inline]
@@ -7,6 +7,30 @@ pub fn is_signed_nbit_integer(n: u8, value: impl Into<i64>) -> bool {
     ((-1) << (n - 1)) <= value && value < (1 << (n - 1))
 }
 
+#[inline]
+pub fn is_register_pair(reg: Register) -> bool {
+    reg as u8 & 1 == 0
+}
+
+#[inline]
+pub fn is_vregister_group(reg: VRegister, lmul: Lmul) -> bool {
+    let registers = lmul.registers();
+    reg as usize & (registers - 1) == 0 && reg as usize + registers <= 32
+}
+
+#[inline]
+pub fn is_vregister_overlap(a: VRegister, a_lmul: Lmul, b: VRegister, b_lmul: Lmul) -> bool {
+    (a as usize) < b as usize + b_lmul.registers() && (b as usize) < a as usize + a_lmul.registers()
+}
+
+#[inline]
+pub fn zcmp_spimm(rlist: RegisterList, stack_adj: u16, register_size: usize) -> u8 {
+    let base = (rlist.registers() * register_size + 15) & !15;
+    let stack_adj = stack_adj as usize;
+    assert!(stack_adj >= base && stack_adj - base <= 48 && stack_adj & 15 == 0);
+    ((stack_adj - base) >> 4) as u8
+}
+
 
 macro_rules! encode {
     ($($e:expr),*) => {
@@ -94,48 +118,134 @@ pub enum Opcode {
     Nmsub   = 0b1001011,
     Nmadd   = 0b1001111,
     OpFp    = 0b1010011,
-    // Reserved = 0b1010111,
+    OpV     = 0b1010111,
     Custom2 = 0b1011011,
     Branch  = 0b1100011,
     Jalr    = 0b1100111,
     // Reserved = 0b1101011,
     Jal     = 0b1101111,
     System  = 0b1110011,
-    // Reserved = 0b1110111,
+    OpVe    = 0b1110111,
     Custom3 = 0b1111011,
 }
 
 pub use Opcode::*;
 
 
-pub struct RType {
+pub trait RegisterIndex: Copy {
+    fn index(self) -> u32;
+}
+
+impl RegisterIndex for Register {
+    #[inline]
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
+impl RegisterIndex for FRegister {
+    #[inline]
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
+impl RegisterIndex for VRegister {
+    #[inline]
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
+impl RegisterIndex for u8 {
+    #[inline]
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
+
+pub struct RType<Rd = Register, Rs1 = Register, Rs2 = Register> {
     pub opcode: Opcode,
     pub funct3: u8,
     pub funct7: u8,
-    pub rd: Register,
-    pub rs1: Register,
-    pub rs2: Register,
+    pub rd: Rd,
+    pub rs1: Rs1,
+    pub rs2: Rs2,
 }
 
-impl RType {
+impl<Rd, Rs1, Rs2> RType<Rd, Rs1, Rs2>
+    where Rd: RegisterIndex, Rs1: RegisterIndex, Rs2: RegisterIndex
+{
     #[inline]
     pub fn encode(self) -> u32 {
         encode!(
             i7(self.funct7 as u32),
-            i5(self.rs2 as u32),
-            i5(self.rs1 as u32),
+            i5(self.rs2.index()),
+            i5(self.rs1.index()),
             i3(self.funct3 as u32),
-            i5(self.rd as u32),
+            i5(self.rd.index()),
             i7(self.opcode as u32)
         )
     }
 }
 
-pub struct IType {
+pub struct R4Type<Rd = FRegister, Rs = FRegister> {
     pub opcode: Opcode,
+    pub funct2: u8,
+    pub funct3: u8,
+    pub rd: Rd,
+    pub rs1: Rs,
+    pub rs2: Rs,
+    pub rs3: Rs,
+}
+
+impl<Rd, Rs> R4Type<Rd, Rs>
+    where Rd: RegisterIndex, Rs: RegisterIndex
+{
+    #[inline]
+    pub fn encode(self) -> u32 {
+        encode!(
+            i5(self.rs3.index()),
+            i2(self.funct2 as u32),
+            i5(self.rs2.index()),
+            i5(self.rs1.index()),
+            i3(self.funct3 as u32),
+            i5(self.rd.index()),
+            i7(self.opcode as u32)
+        )
+    }
+}
+
+pub struct AType {
     pub funct3: u8,
+    pub funct5: u8,
+    pub ordering: AmoOrdering,
     pub rd: Register,
-    pub rs: Register,
+    pub rs1: Register,
+    pub rs2: Register,
+}
+
+impl AType {
+    #[inline]
+    pub fn encode(self) -> u32 {
+        encode!(
+            i5(self.funct5 as u32),
+            i2(self.ordering as u32),
+            i5(self.rs2.index()),
+            i5(self.rs1.index()),
+            i3(self.funct3 as u32),
+            i5(self.rd.index()),
+            i7(Opcode::Amo as u32)
+        )
+    }
+}
+
+pub struct IType<Rd = Register, Rs = Register> {
+    pub opcode: Opcode,
+    pub funct3: u8,
+    pub rd: Rd,
+    pub rs: Rs,
     pub imm12: i16,
 }
 
@@ -150,37 +260,139 @@ impl IType {
             imm12: 0,
         }
     }
+}
 
+impl<Rd, Rs> IType<Rd, Rs>
+    where Rd: RegisterIndex, Rs: RegisterIndex
+{
     #[track_caller]
     #[inline]
     pub fn encode(self) -> u32 {
         assert!(is_signed_nbit_integer(12, self.imm12));
         encode!(
             i12(self.imm12 as u32),
-            i5(self.rs as u32),
+            i5(self.rs.index()),
             i3(self.funct3 as u32),
-            i5(self.rd as u32),
+            i5(self.rd.index()),
             i7(self.opcode as u32)
         )
     }
 }
 
-pub struct SType {
+pub struct CsrType<Rs = Register> {
+    pub funct3: u8,
+    pub rd: Register,
+    pub rs: Rs,
+    pub csr: Csr,
+}
+
+impl<Rs> CsrType<Rs> where Rs: RegisterIndex {
+    #[inline]
+    pub fn encode(self) -> u32 {
+        encode!(
+            i12(self.csr.address() as u32),
+            i5(self.rs.index()),
+            i3(self.funct3 as u32),
+            i5(self.rd.index()),
+            i7(Opcode::System as u32)
+        )
+    }
+}
+
+pub struct VArithType<Vd = VRegister, Rs1 = VRegister> {
+    pub funct6: u8,
+    pub funct3: u8,
+    pub vm: VMask,
+    pub vd: Vd,
+    pub vs2: VRegister,
+    pub rs1: Rs1,
+}
+
+impl<Vd, Rs1> VArithType<Vd, Rs1> where Vd: RegisterIndex, Rs1: RegisterIndex {
+    #[inline]
+    pub fn encode(self) -> u32 {
+        encode!(
+            i6(self.funct6 as u32),
+            i1(self.vm as u32),
+            i5(self.vs2.index()),
+            i5(self.rs1.index()),
+            i3(self.funct3 as u32),
+            i5(self.vd.index()),
+            i7(Opcode::OpV as u32)
+        )
+    }
+}
+
+pub struct VeArithType<Rs1 = VRegister> {
+    pub funct6: u8,
+    pub funct3: u8,
+    pub vm: VMask,
+    pub vd: VRegister,
+    pub vs2: VRegister,
+    pub rs1: Rs1,
+}
+
+impl<Rs1> VeArithType<Rs1> where Rs1: RegisterIndex {
+    #[inline]
+    pub fn encode(self) -> u32 {
+        encode!(
+            i6(self.funct6 as u32),
+            i1(self.vm as u32),
+            i5(self.vs2.index()),
+            i5(self.rs1.index()),
+            i3(self.funct3 as u32),
+            i5(self.vd.index()),
+            i7(Opcode::OpVe as u32)
+        )
+    }
+}
+
+pub struct VMemType<Rs2 = u8> {
+    pub opcode: Opcode,
+    pub nf: u8,
+    pub mop: u8,
+    pub vm: VMask,
+    pub rs2: Rs2,
+    pub rs1: Register,
+    pub width: u8,
+    pub vd: VRegister,
+}
+
+impl<Rs2> VMemType<Rs2> where Rs2: RegisterIndex {
+    #[inline]
+    pub fn encode(self) -> u32 {
+        encode!(
+            i3(self.nf as u32),
+            i1(0),
+            i2(self.mop as u32),
+            i1(self.vm as u32),
+            i5(self.rs2.index()),
+            i5(self.rs1.index()),
+            i3(self.width as u32),
+            i5(self.vd.index()),
+            i7(self.opcode as u32)
+        )
+    }
+}
+
+pub struct SType<Rs = Register> {
     pub opcode: Opcode,
     pub funct3: u8,
-    pub rs: Register,
+    pub rs: Rs,
     pub base: Register,
     pub imm12: i16,
 }
 
-impl SType {
+impl<Rs> SType<Rs>
+    where Rs: RegisterIndex
+{
     #[track_caller]
     #[inline]
     pub fn encode(self) -> u32 {
         assert!(is_signed_nbit_integer(12, self.imm12));
         encode!(
             i7((self.imm12 >> 5) as u32),
-            i5(self.rs as u32),
+            i5(self.rs.index()),
             i5(self.base as u32),
             i3(self.funct3 as u32),
             i5(self.imm12 as u32),
@@ -351,3 +563,22 @@ impl CjType {
         ) as u16
     }
 }
+
+pub struct CmPushPopType {
+    pub funct5: u8,
+    pub rlist: RegisterList,
+    pub spimm: u8,
+}
+
+impl CmPushPopType {
+    #[inline]
+    pub fn encode(self) -> u16 {
+        encode!(
+            i3(0b101),
+            i5(self.funct5 as u32),
+            i4(self.rlist as u32),
+            i2(self.spimm as u32),
+            i2(0b10)
+        ) as u16
+    }
+}
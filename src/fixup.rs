@@ -12,6 +12,8 @@ pub enum FixupKind {
     CBranch,
     JumpFar,
     Load,
+    Address32(u32),
+    Address64(u64),
 }
 
 impl FixupKind {
@@ -34,6 +36,19 @@ impl FixupKind {
         }
     }
 
+    #[inline]
+    fn apply_fixup_pair(&self, auipc: u32, itype: u32, offset: i64) -> (u32, u32) {
+        match *self {
+            FixupKind::JumpFar |
+            FixupKind::Load => {
+                assert!(is_signed_nbit_integer(32, offset));
+                let (upper, lower) = to_i20_i12_imm_pair(offset as i32);
+                (apply_auipc_fixup(auipc, upper), apply_itype_fixup(itype, lower))
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[inline]
     fn apply_fixup_16(&self, instruction: u16, offset: i64) -> u16 {
         match *self {
@@ -52,6 +67,15 @@ impl FixupKind {
             _ => unreachable!(),
         }
     }
+
+    #[inline]
+    pub(crate) fn apply_fixup_address(&self, offset: i64) -> u64 {
+        match *self {
+            FixupKind::Address32(address) => address.wrapping_add(offset as u32) as u64,
+            FixupKind::Address64(address) => address.wrapping_add(offset as u64),
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl<Emit> mitte_core::FixupKind<Emit> for FixupKind
@@ -76,16 +100,25 @@ impl<Emit> mitte_core::FixupKind<Emit> for FixupKind
             }
             FixupKind::JumpFar |
             FixupKind::Load => {
-                assert!(is_signed_nbit_integer(32, offset));
-                let (upper, lower) = to_i20_i12_imm_pair(offset as i32);
+                let auipc = u32::from_le_bytes(*emit.get_mut_array::<4>(range.start)?);
+                let itype = u32::from_le_bytes(*emit.get_mut_array::<4>(range.start + 4)?);
+                let (auipc, itype) = self.apply_fixup_pair(auipc, itype, offset);
 
                 let auipc_buffer = emit.get_mut_array::<4>(range.start)?;
-                let auipc = u32::from_le_bytes(*auipc_buffer);
-                auipc_buffer.copy_from_slice(&apply_auipc_fixup(auipc, upper).to_le_bytes());
+                auipc_buffer.copy_from_slice(&auipc.to_le_bytes());
 
                 let itype_buffer = emit.get_mut_array::<4>(range.start + 4)?;
-                let itype = u32::from_le_bytes(*itype_buffer);
-                itype_buffer.copy_from_slice(&apply_itype_fixup(itype, lower).to_le_bytes());
+                itype_buffer.copy_from_slice(&itype.to_le_bytes());
+                Ok(())
+            }
+            FixupKind::Address32(_) => {
+                let buffer = emit.get_mut_array::<4>(range.start)?;
+                buffer.copy_from_slice(&(self.apply_fixup_address(offset) as u32).to_le_bytes());
+                Ok(())
+            }
+            FixupKind::Address64(_) => {
+                let buffer = emit.get_mut_array::<8>(range.start)?;
+                buffer.copy_from_slice(&self.apply_fixup_address(offset).to_le_bytes());
                 Ok(())
             }
         }
@@ -143,3 +176,52 @@ fn encode_c_jump_offset(offset: i16) -> u16 {
 fn encode_c_branch_offset(offset: i16) -> u16 {
     CbType { offset, ..CbType::null() }.encode()
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+    use crate::{rv32d, rv32f, rv32i};
+    #[test]
+    fn test_address32_fixup() {
+        assert_eq!(0x1020, FixupKind::Address32(0x1000).apply_fixup_address(0x20));
+        assert_eq!(0x0800, FixupKind::Address32(0x1000).apply_fixup_address(-0x800));
+        assert_eq!(0xffff_ffff, FixupKind::Address32(0xffff_fff0).apply_fixup_address(0xf));
+    }
+
+    #[test]
+    fn test_address64_fixup() {
+        assert_eq!(0x1_0000_0020, FixupKind::Address64(0x1_0000_0000).apply_fixup_address(0x20));
+        assert_eq!(0xffff_fff0, FixupKind::Address64(0x1_0000_0000).apply_fixup_address(-0x10));
+    }
+
+    #[test]
+    fn test_load_fixup_fld() {
+        let auipc = rv32i::auipc(T0, 0);
+        let fld = rv32d::fld(Fa0, T0, 0);
+        assert_eq!((0x00000297, 0x0102b507), FixupKind::Load.apply_fixup_pair(auipc, fld, 16));
+        assert_eq!((0x00000297, 0xfe02b507), FixupKind::Load.apply_fixup_pair(auipc, fld, -32));
+        assert_eq!((0x00001297, 0x8002b507), FixupKind::Load.apply_fixup_pair(auipc, fld, 0x800));
+        assert_eq!((0xfffff297, 0x7ff2b507), FixupKind::Load.apply_fixup_pair(auipc, fld, -0x801));
+    }
+
+    #[test]
+    fn test_load_fixup_flw() {
+        let auipc = rv32i::auipc(T0, 0);
+        let flw = rv32f::flw(Ft1, T0, 0);
+        assert_eq!((0x12345297, 0x6782a087), FixupKind::Load.apply_fixup_pair(auipc, flw, 0x12345678));
+        let auipc = rv32i::auipc(S1, 0);
+        let flw = rv32f::flw(Fs1, S1, 0);
+        assert_eq!((0x80000497, 0x0004a487), FixupKind::Load.apply_fixup_pair(auipc, flw, -0x80000000));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_load_fixup_out_of_range() {
+        let auipc = rv32i::auipc(T0, 0);
+        let fld = rv32d::fld(Fa0, T0, 0);
+        FixupKind::Load.apply_fixup_pair(auipc, fld, 0x80000000);
+    }
+}
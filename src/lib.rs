@@ -4,20 +4,90 @@ pub mod fixup;
 pub use self::types::*;
 
 pub mod rv32i;
+pub mod rv32priv;
 pub mod rv32m;
+pub mod rv32a;
+pub mod rv32f;
+pub mod rv32d;
+pub mod rv32q;
 pub mod rv32c;
+pub mod rv32h;
+pub mod rv32v;
+pub mod rv32zabha;
+pub mod rv32zacas;
 pub mod rv32zba;
 pub mod rv32zbb;
 pub mod rv32zbc;
+pub mod rv32zbkb;
+pub mod rv32zbkc;
+pub mod rv32zbkx;
 pub mod rv32zbs;
+pub mod rv32zcb;
+pub mod rv32zcmp;
+pub mod rv32zcmt;
+pub mod rv32zfa;
+pub mod rv32zfh;
+pub mod rv32zfhmin;
+pub mod rv32zicond;
+pub mod rv32zicsr;
+pub mod rv32zifencei;
+pub mod rv32zihintpause;
+pub mod rv32zknd;
+pub mod rv32zkne;
+pub mod rv32zknh;
+pub mod rv32zkr;
+pub mod rv32zksed;
+pub mod rv32zksh;
+pub mod rv32zvbb;
+pub mod rv32zvbc;
+pub mod rv32zvkg;
+pub mod rv32zvkned;
+pub mod rv32zvknh;
+pub mod rv32zvksed;
+pub mod rv32zvksh;
 
 pub mod rv64i;
+pub mod rv64priv;
 pub mod rv64m;
+pub mod rv64a;
+pub mod rv64f;
+pub mod rv64d;
+pub mod rv64q;
 pub mod rv64c;
+pub mod rv64h;
+pub mod rv64v;
+pub mod rv64zabha;
+pub mod rv64zacas;
 pub mod rv64zba;
 pub mod rv64zbb;
 pub mod rv64zbc;
+pub mod rv64zbkb;
+pub mod rv64zbkc;
+pub mod rv64zbkx;
 pub mod rv64zbs;
+pub mod rv64zcb;
+pub mod rv64zcmp;
+pub mod rv64zcmt;
+pub mod rv64zfa;
+pub mod rv64zfh;
+pub mod rv64zfhmin;
+pub mod rv64zicond;
+pub mod rv64zicsr;
+pub mod rv64zifencei;
+pub mod rv64zihintpause;
+pub mod rv64zknd;
+pub mod rv64zkne;
+pub mod rv64zknh;
+pub mod rv64zkr;
+pub mod rv64zksed;
+pub mod rv64zksh;
+pub mod rv64zvbb;
+pub mod rv64zvbc;
+pub mod rv64zvkg;
+pub mod rv64zvkned;
+pub mod rv64zvknh;
+pub mod rv64zvksed;
+pub mod rv64zvksh;
 
 mod encoding;
 mod macros;
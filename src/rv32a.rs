@@ -0,0 +1,81 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_amoadd_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoadd_w;
+        emit_amoand_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoand_w;
+        emit_amomax_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomax_w;
+        emit_amomaxu_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomaxu_w;
+        emit_amomin_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomin_w;
+        emit_amominu_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amominu_w;
+        emit_amoor_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoor_w;
+        emit_amoswap_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoswap_w;
+        emit_amoxor_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoxor_w;
+        emit_lr_w(rd: Register, base: Register, ordering: AmoOrdering) => lr_w;
+        emit_sc_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => sc_w;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn lr_w(rd: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b00010, ordering, rd, rs1: base, rs2: Register::Zero }.encode()
+}
+
+#[inline]
+pub fn sc_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b00011, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoadd_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b00000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoswap_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b00001, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoxor_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b00100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoor_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b01000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoand_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b01100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomin_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b10000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomax_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b10100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amominu_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b11000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomaxu_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b11100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
@@ -49,6 +49,19 @@ pub trait Emit: EmitSlice {
         )
     }
 
+    fn emit_c_jal_label<Label>(&mut self, label: &mut Label) -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        self.emit_branch(
+            label,
+            FixupKind::CJump,
+            |e, offset| {
+                e.emit_c_jal(offset as i16)
+            },
+        )
+    }
+
     forward! {
         emit_c_add(rd: Register, rs: Register) => add;
         emit_c_addi(rd: Register, imm: i8) => addi;
@@ -59,7 +72,16 @@ pub trait Emit: EmitSlice {
         emit_c_beqz(rs: CRegister, offset: i16) => beqz;
         emit_c_bnez(rs: CRegister, offset: i16) => bnez;
         emit_c_ebreak() => ebreak;
+        emit_c_fld(rd: CFRegister, base: CRegister, offset: u8) => fld;
+        emit_c_fldsp(rd: FRegister, offset: u16) => fldsp;
+        emit_c_flw(rd: CFRegister, base: CRegister, offset: u8) => flw;
+        emit_c_flwsp(rd: FRegister, offset: u8) => flwsp;
+        emit_c_fsd(rs: CFRegister, base: CRegister, offset: u8) => fsd;
+        emit_c_fsdsp(rs: FRegister, offset: u16) => fsdsp;
+        emit_c_fsw(rs: CFRegister, base: CRegister, offset: u8) => fsw;
+        emit_c_fswsp(rs: FRegister, offset: u8) => fswsp;
         emit_c_j(offset: i16) => j;
+        emit_c_jal(offset: i16) => jal;
         emit_c_jalr(rs: Register) => jalr;
         emit_c_jr(rs: Register) => jr;
         emit_c_li(rd: Register, imm: i8) => li;
@@ -101,6 +123,18 @@ pub fn addi4spn(rd: CRegister, imm: u16) -> u16 {
     ) as u16
 }
 
+#[inline]
+pub fn fld(rd: CFRegister, base: CRegister, offset: u8) -> u16 {
+    encode!(
+        i3(0b001),
+        i3((offset >> 3) as u32),
+        i3(base as u32),
+        i2((offset >> 6) as u32),
+        i3(rd as u32),
+        i2(0b00)
+    ) as u16
+}
+
 #[inline]
 pub fn lw(rd: CRegister, base: CRegister, offset: u8) -> u16 {
     encode!(
@@ -114,6 +148,31 @@ pub fn lw(rd: CRegister, base: CRegister, offset: u8) -> u16 {
     ) as u16
 }
 
+#[inline]
+pub fn flw(rd: CFRegister, base: CRegister, offset: u8) -> u16 {
+    encode!(
+        i3(0b011),
+        i3((offset >> 3) as u32),
+        i3(base as u32),
+        i1((offset >> 2) as u32),
+        i1((offset >> 6) as u32),
+        i3(rd as u32),
+        i2(0b00)
+    ) as u16
+}
+
+#[inline]
+pub fn fsd(rs: CFRegister, base: CRegister, offset: u8) -> u16 {
+    encode!(
+        i3(0b101),
+        i3((offset >> 3) as u32),
+        i3(base as u32),
+        i2((offset >> 6) as u32),
+        i3(rs as u32),
+        i2(0b00)
+    ) as u16
+}
+
 #[inline]
 pub fn sw(rd: CRegister, base: CRegister, offset: u8) -> u16 {
     encode!(
@@ -127,6 +186,19 @@ pub fn sw(rd: CRegister, base: CRegister, offset: u8) -> u16 {
     ) as u16
 }
 
+#[inline]
+pub fn fsw(rs: CFRegister, base: CRegister, offset: u8) -> u16 {
+    encode!(
+        i3(0b111),
+        i3((offset >> 3) as u32),
+        i3(base as u32),
+        i1((offset >> 2) as u32),
+        i1((offset >> 6) as u32),
+        i3(rs as u32),
+        i2(0b00)
+    ) as u16
+}
+
 #[inline]
 pub fn nop() -> u16 {
     encode!(i3(0b000), i1(0), i5(0), i5(0), i2(0b01)) as u16
@@ -137,6 +209,12 @@ pub fn addi(rd: Register, imm: i8) -> u16 {
     CiType { op: 0b01, funct3: 0b000, rd, imm }.encode()
 }
 
+/// `c.jal` only exists on RV32; the same encoding is `c.addiw` on RV64.
+#[inline]
+pub fn jal(offset: i16) -> u16 {
+    CjType { op: 0b01, funct3: 0b001, offset }.encode()
+}
+
 #[inline]
 pub fn li(rd: Register, imm: i8) -> u16 {
     CiType { op: 0b01, funct3: 0b010, rd, imm }.encode()
@@ -237,6 +315,18 @@ pub fn slli(rd: Register, shamt: u8) -> u16 {
     CiType { op: 0b10, funct3: 0b000, rd, imm: shamt as i8 & 0x1f }.encode()
 }
 
+#[inline]
+pub fn fldsp(rd: FRegister, offset: u16) -> u16 {
+    encode!(
+        i3(0b001),
+        i1((offset >> 5) as u32),
+        i5(rd as u32),
+        i2((offset >> 3) as u32),
+        i3((offset >> 6) as u32),
+        i2(0b10)
+    ) as u16
+}
+
 #[inline]
 pub fn lwsp(rd: Register, offset: u8) -> u16 {
     encode!(
@@ -249,6 +339,18 @@ pub fn lwsp(rd: Register, offset: u8) -> u16 {
     ) as u16
 }
 
+#[inline]
+pub fn flwsp(rd: FRegister, offset: u8) -> u16 {
+    encode!(
+        i3(0b011),
+        i1((offset >> 5) as u32),
+        i5(rd as u32),
+        i3((offset >> 2) as u32),
+        i2((offset >> 6) as u32),
+        i2(0b10)
+    ) as u16
+}
+
 #[inline]
 pub fn jr(rs: Register) -> u16 {
     CrType { op: 0b10, funct4: 0b1000, rd: rs, rs: Register::Zero }.encode()
@@ -274,6 +376,17 @@ pub fn add(rd: Register, rs: Register) -> u16 {
     CrType { op: 0b10, funct4: 0b1001, rd, rs }.encode()
 }
 
+#[inline]
+pub fn fsdsp(rs: FRegister, offset: u16) -> u16 {
+    encode!(
+        i3(0b101),
+        i3((offset >> 3) as u32),
+        i3((offset >> 6) as u32),
+        i5(rs as u32),
+        i2(0b10)
+    ) as u16
+}
+
 #[inline]
 pub fn swsp(rs: Register, offset: u8) -> u16 {
     encode!(
@@ -284,3 +397,14 @@ pub fn swsp(rs: Register, offset: u8) -> u16 {
         i2(0b10)
     ) as u16
 }
+
+#[inline]
+pub fn fswsp(rs: FRegister, offset: u8) -> u16 {
+    encode!(
+        i3(0b111),
+        i4((offset >> 2) as u32),
+        i2((offset >> 6) as u32),
+        i5(rs as u32),
+        i2(0b10)
+    ) as u16
+}
@@ -0,0 +1,215 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32i::Emit as _;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_fld_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        self.emit_branch(
+            label,
+            FixupKind::Load,
+            |e, offset| {
+                let (upper, lower) = to_i20_i12_imm_pair(offset as i32);
+                e.emit_auipc(rt, upper)?;
+                e.emit_fld(rd, rt, lower)
+            },
+        )
+    }
+
+    forward! {
+        emit_fabs_d(rd: FRegister, rs: FRegister) => fabs_d;
+        emit_fadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fadd_d;
+        emit_fclass_d(rd: Register, rs: FRegister) => fclass_d;
+        emit_fcvt_d_s(rd: FRegister, rs: FRegister) => fcvt_d_s;
+        emit_fcvt_d_w(rd: FRegister, rs: Register) => fcvt_d_w;
+        emit_fcvt_d_wu(rd: FRegister, rs: Register) => fcvt_d_wu;
+        emit_fcvt_s_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => fcvt_s_d;
+        emit_fcvt_w_d(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_w_d;
+        emit_fcvt_wu_d(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_wu_d;
+        emit_fdiv_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fdiv_d;
+        emit_feq_d(rd: Register, rs1: FRegister, rs2: FRegister) => feq_d;
+        emit_fld(rd: FRegister, base: Register, offset: i16) => fld;
+        emit_fle_d(rd: Register, rs1: FRegister, rs2: FRegister) => fle_d;
+        emit_flt_d(rd: Register, rs1: FRegister, rs2: FRegister) => flt_d;
+        emit_fmadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmadd_d;
+        emit_fmax_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmax_d;
+        emit_fmin_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmin_d;
+        emit_fmsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmsub_d;
+        emit_fmul_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fmul_d;
+        emit_fmv_d(rd: FRegister, rs: FRegister) => fmv_d;
+        emit_fneg_d(rd: FRegister, rs: FRegister) => fneg_d;
+        emit_fnmadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmadd_d;
+        emit_fnmsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmsub_d;
+        emit_fsd(rs: FRegister, base: Register, offset: i16) => fsd;
+        emit_fsgnj_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnj_d;
+        emit_fsgnjn_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjn_d;
+        emit_fsgnjx_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjx_d;
+        emit_fsqrt_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => fsqrt_d;
+        emit_fsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fsub_d;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+#[inline]
+pub fn fld(rd: FRegister, base: Register, offset: i16) -> u32 {
+    IType { opcode: LoadFp, funct3: 0b011, rd, rs: base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fsd(rs: FRegister, base: Register, offset: i16) -> u32 {
+    SType { opcode: StoreFp, funct3: 0b011, rs, base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fmadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Madd, funct2: 0b01, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fmsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Msub, funct2: 0b01, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmsub, funct2: 0b01, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmadd, funct2: 0b01, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmul_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fdiv_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsqrt_d(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0101101, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fsgnj_d(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjn_d(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjx_d(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmin_d(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmax_d(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fcvt_s_d(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100000, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_d_s(rd: FRegister, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0100001, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn feq_d(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b1010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn flt_d(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fle_d(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fclass_d(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1110001, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_w_d(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100001, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_wu_d(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100001, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_d_w(rd: FRegister, rs: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1101001, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_d_wu(rd: FRegister, rs: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1101001, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_d(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnj_d(rd, rs, rs)
+}
+
+#[inline]
+pub fn fneg_d(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjn_d(rd, rs, rs)
+}
+
+#[inline]
+pub fn fabs_d(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjx_d(rd, rs, rs)
+}
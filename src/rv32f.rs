@@ -0,0 +1,216 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32i::Emit as _;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flw_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        self.emit_branch(
+            label,
+            FixupKind::Load,
+            |e, offset| {
+                let (upper, lower) = to_i20_i12_imm_pair(offset as i32);
+                e.emit_auipc(rt, upper)?;
+                e.emit_flw(rd, rt, lower)
+            },
+        )
+    }
+
+    forward! {
+        emit_fabs_s(rd: FRegister, rs: FRegister) => fabs_s;
+        emit_fadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fadd_s;
+        emit_fclass_s(rd: Register, rs: FRegister) => fclass_s;
+        emit_fcvt_s_w(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_s_w;
+        emit_fcvt_s_wu(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_s_wu;
+        emit_fcvt_w_s(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_w_s;
+        emit_fcvt_wu_s(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_wu_s;
+        emit_fdiv_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fdiv_s;
+        emit_feq_s(rd: Register, rs1: FRegister, rs2: FRegister) => feq_s;
+        emit_fle_s(rd: Register, rs1: FRegister, rs2: FRegister) => fle_s;
+        emit_flt_s(rd: Register, rs1: FRegister, rs2: FRegister) => flt_s;
+        emit_flw(rd: FRegister, base: Register, offset: i16) => flw;
+        emit_fmadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmadd_s;
+        emit_fmax_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmax_s;
+        emit_fmin_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmin_s;
+        emit_fmsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmsub_s;
+        emit_fmul_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fmul_s;
+        emit_fmv_s(rd: FRegister, rs: FRegister) => fmv_s;
+        emit_fmv_w_x(rd: FRegister, rs: Register) => fmv_w_x;
+        emit_fmv_x_w(rd: Register, rs: FRegister) => fmv_x_w;
+        emit_fneg_s(rd: FRegister, rs: FRegister) => fneg_s;
+        emit_fnmadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmadd_s;
+        emit_fnmsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmsub_s;
+        emit_fsgnj_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnj_s;
+        emit_fsgnjn_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjn_s;
+        emit_fsgnjx_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjx_s;
+        emit_fsqrt_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => fsqrt_s;
+        emit_fsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fsub_s;
+        emit_fsw(rs: FRegister, base: Register, offset: i16) => fsw;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn flw(rd: FRegister, base: Register, offset: i16) -> u32 {
+    IType { opcode: LoadFp, funct3: 0b010, rd, rs: base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fsw(rs: FRegister, base: Register, offset: i16) -> u32 {
+    SType { opcode: StoreFp, funct3: 0b010, rs, base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fmadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Madd, funct2: 0b00, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fmsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Msub, funct2: 0b00, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmsub, funct2: 0b00, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmadd, funct2: 0b00, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmul_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fdiv_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsqrt_s(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0101100, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fsgnj_s(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjn_s(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjx_s(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmin_s(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmax_s(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fcvt_w_s(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100000, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_wu_s(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100000, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_x_w(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1110000, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn feq_s(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b1010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn flt_s(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fle_s(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fclass_s(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1110000, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_s_w(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101000, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_s_wu(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101000, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_w_x(rd: FRegister, rs: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1111000, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_s(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnj_s(rd, rs, rs)
+}
+
+#[inline]
+pub fn fneg_s(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjn_s(rd, rs, rs)
+}
+
+#[inline]
+pub fn fabs_s(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjx_s(rd, rs, rs)
+}
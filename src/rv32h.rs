@@ -0,0 +1,192 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_hfence_gvma(gaddr: Option<Register>, vmid: Option<Register>) => hfence_gvma;
+        emit_hfence_vvma(vaddr: Option<Register>, asid: Option<Register>) => hfence_vvma;
+        emit_hinval_gvma(gaddr: Option<Register>, vmid: Option<Register>) => hinval_gvma;
+        emit_hinval_vvma(vaddr: Option<Register>, asid: Option<Register>) => hinval_vvma;
+        emit_hlv_b(rd: Register, base: Register) => hlv_b;
+        emit_hlv_bu(rd: Register, base: Register) => hlv_bu;
+        emit_hlv_h(rd: Register, base: Register) => hlv_h;
+        emit_hlv_hu(rd: Register, base: Register) => hlv_hu;
+        emit_hlv_w(rd: Register, base: Register) => hlv_w;
+        emit_hlvx_hu(rd: Register, base: Register) => hlvx_hu;
+        emit_hlvx_wu(rd: Register, base: Register) => hlvx_wu;
+        emit_hsv_b(rs: Register, base: Register) => hsv_b;
+        emit_hsv_h(rs: Register, base: Register) => hsv_h;
+        emit_hsv_w(rs: Register, base: Register) => hsv_w;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn hlv_b(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110000, rd, rs1: base, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn hlv_bu(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110000, rd, rs1: base, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn hlv_h(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110010, rd, rs1: base, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn hlv_hu(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110010, rd, rs1: base, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn hlvx_hu(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110010, rd, rs1: base, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn hlv_w(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110100, rd, rs1: base, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn hlvx_wu(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110100, rd, rs1: base, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn hsv_b(rs: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110001, rd: Register::Zero, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn hsv_h(rs: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110011, rd: Register::Zero, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn hsv_w(rs: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110101, rd: Register::Zero, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn hfence_vvma(vaddr: Option<Register>, asid: Option<Register>) -> u32 {
+    let rs1 = vaddr.unwrap_or(Register::Zero);
+    let rs2 = asid.unwrap_or(Register::Zero);
+    RType { opcode: System, funct3: 0b000, funct7: 0b0010001, rd: Register::Zero, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn hfence_gvma(gaddr: Option<Register>, vmid: Option<Register>) -> u32 {
+    let rs1 = gaddr.unwrap_or(Register::Zero);
+    let rs2 = vmid.unwrap_or(Register::Zero);
+    RType { opcode: System, funct3: 0b000, funct7: 0b0110001, rd: Register::Zero, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn hinval_vvma(vaddr: Option<Register>, asid: Option<Register>) -> u32 {
+    let rs1 = vaddr.unwrap_or(Register::Zero);
+    let rs2 = asid.unwrap_or(Register::Zero);
+    RType { opcode: System, funct3: 0b000, funct7: 0b0010011, rd: Register::Zero, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn hinval_gvma(gaddr: Option<Register>, vmid: Option<Register>) -> u32 {
+    let rs1 = gaddr.unwrap_or(Register::Zero);
+    let rs2 = vmid.unwrap_or(Register::Zero);
+    RType { opcode: System, funct3: 0b000, funct7: 0b0110011, rd: Register::Zero, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_hfence_gvma() {
+        assert_eq!(0x62000073, hfence_gvma(None, None));
+        assert_eq!(0x62050073, hfence_gvma(Some(A0), None));
+        assert_eq!(0x62b50073, hfence_gvma(Some(A0), Some(A1)));
+    }
+
+    #[test]
+    fn test_hfence_vvma() {
+        assert_eq!(0x22000073, hfence_vvma(None, None));
+        assert_eq!(0x22050073, hfence_vvma(Some(A0), None));
+        assert_eq!(0x22b50073, hfence_vvma(Some(A0), Some(A1)));
+    }
+
+    #[test]
+    fn test_hinval_gvma() {
+        assert_eq!(0x66000073, hinval_gvma(None, None));
+        assert_eq!(0x66050073, hinval_gvma(Some(A0), None));
+        assert_eq!(0x66b50073, hinval_gvma(Some(A0), Some(A1)));
+    }
+
+    #[test]
+    fn test_hinval_vvma() {
+        assert_eq!(0x26000073, hinval_vvma(None, None));
+        assert_eq!(0x26050073, hinval_vvma(Some(A0), None));
+        assert_eq!(0x26b50073, hinval_vvma(Some(A0), Some(A1)));
+    }
+
+    #[test]
+    fn test_hlv_b() {
+        assert_eq!(0x6005c573, hlv_b(A0, A1));
+    }
+
+    #[test]
+    fn test_hlv_bu() {
+        assert_eq!(0x6015c573, hlv_bu(A0, A1));
+    }
+
+    #[test]
+    fn test_hlv_h() {
+        assert_eq!(0x6405c573, hlv_h(A0, A1));
+    }
+
+    #[test]
+    fn test_hlv_hu() {
+        assert_eq!(0x6415c573, hlv_hu(A0, A1));
+    }
+
+    #[test]
+    fn test_hlv_w() {
+        assert_eq!(0x6805c573, hlv_w(A0, A1));
+    }
+
+    #[test]
+    fn test_hlvx_hu() {
+        assert_eq!(0x6435c573, hlvx_hu(A0, A1));
+    }
+
+    #[test]
+    fn test_hlvx_wu() {
+        assert_eq!(0x6835c573, hlvx_wu(A0, A1));
+    }
+
+    #[test]
+    fn test_hsv_b() {
+        assert_eq!(0x62a5c073, hsv_b(A0, A1));
+    }
+
+    #[test]
+    fn test_hsv_h() {
+        assert_eq!(0x66a5c073, hsv_h(A0, A1));
+    }
+
+    #[test]
+    fn test_hsv_w() {
+        assert_eq!(0x6aa5c073, hsv_w(A0, A1));
+    }
+}
@@ -364,6 +364,15 @@ pub trait Emit: EmitSlice {
         )
     }
 
+    fn emit_select_mask(&mut self, rd: Register, cond: Register, a: Register, b: Register, tmp: Register)
+        -> Result<(), Self::Error>
+    {
+        for word in select_mask(rd, cond, a, b, tmp) {
+            self.emit_slice(&word.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
     forward! {
         emit_add(rd: Register, rs1: Register, rs2: Register) => add;
         emit_addi(rd: Register, rs: Register, imm12: i16) => addi;
@@ -388,6 +397,8 @@ pub trait Emit: EmitSlice {
         emit_bnez(rs: Register, offset: i16) => bnez;
         emit_ebreak() => ebreak;
         emit_ecall() => ecall;
+        emit_fence(pred: FenceSet, succ: FenceSet) => fence;
+        emit_fence_tso() => fence_tso;
         emit_j(offset: i32) => j;
         emit_jal(rd: Register, offset: i32) => jal;
         emit_jalr(rd: Register, base: Register, offset: i16) => jalr;
@@ -641,6 +652,16 @@ pub fn ebreak() -> u32 {
     IType { opcode: System, imm12: 1, ..IType::null() }.encode()
 }
 
+#[inline]
+pub fn fence(pred: FenceSet, succ: FenceSet) -> u32 {
+    IType { opcode: MiscMem, imm12: ((pred.bits() << 4) | succ.bits()) as i16, ..IType::null() }.encode()
+}
+
+#[inline]
+pub fn fence_tso() -> u32 {
+    IType { opcode: MiscMem, imm12: 0xf833_u16 as i16, ..IType::null() }.encode()
+}
+
 #[inline]
 pub fn unimp() -> u32 {
     IType { opcode: System, funct3: 0b001, imm12: 0xfc00_u16 as i16, ..IType::null() }.encode()
@@ -713,12 +734,12 @@ pub fn bgez(rs: Register, offset: i16) -> u32 {
 
 #[inline]
 pub fn bltz(rs: Register, offset: i16) -> u32 {
-    blt(Register::Zero, rs, offset)
+    blt(rs, Register::Zero, offset)
 }
 
 #[inline]
 pub fn bgtz(rs: Register, offset: i16) -> u32 {
-    blt(rs, Register::Zero, offset)
+    blt(Register::Zero, rs, offset)
 }
 
 #[inline]
@@ -755,3 +776,107 @@ pub fn jr(rs: Register) -> u32 {
 pub fn ret() -> u32 {
     jalr(Register::Zero, Register::Ra, 0)
 }
+
+
+fn select_mask(rd: Register, cond: Register, a: Register, b: Register, tmp: Register) -> Vec<u32> {
+    assert!(tmp != rd && tmp != cond && tmp != a && tmp != b);
+    if a == b {
+        return vec![mv(rd, a)];
+    }
+    // `tmp` holds the mask because `rd` may alias any of the sources. The source that is
+    // masked second must not be `rd`, so invert the mask if `rd` is `b`.
+    let (mask, first, second) = if rd == b {
+        (seqz(tmp, cond), b, a)
+    } else {
+        (snez(tmp, cond), a, b)
+    };
+    vec![
+        mask,
+        neg(tmp, tmp),
+        and(rd, first, tmp),
+        not(tmp, tmp),
+        and(tmp, second, tmp),
+        or(rd, rd, tmp),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    #[test]
+    fn test_bgtz() {
+        assert_eq!(0xfe6042e3, bgtz(T1, -28));
+    }
+
+    #[test]
+    fn test_bltz() {
+        assert_eq!(0xfe0342e3, bltz(T1, -28));
+    }
+
+    #[test]
+    fn test_fence_tso() {
+        assert_eq!(0x8330000f, fence_tso());
+    }
+
+    #[test]
+    fn test_select_mask() {
+        assert_eq!(vec![
+            0x00b032b3, // snez t0, a1
+            0x405002b3, // neg t0, t0
+            0x00567533, // and a0, a2, t0
+            0xfff2c293, // not t0, t0
+            0x0056f2b3, // and t0, a3, t0
+            0x00556533, // or a0, a0, t0
+        ], select_mask(A0, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_mask_rd_a() {
+        assert_eq!(vec![
+            0x00b032b3, // snez t0, a1
+            0x405002b3, // neg t0, t0
+            0x00567633, // and a2, a2, t0
+            0xfff2c293, // not t0, t0
+            0x0056f2b3, // and t0, a3, t0
+            0x00566633, // or a2, a2, t0
+        ], select_mask(A2, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_mask_rd_b() {
+        assert_eq!(vec![
+            0x0015b293, // seqz t0, a1
+            0x405002b3, // neg t0, t0
+            0x0056f6b3, // and a3, a3, t0
+            0xfff2c293, // not t0, t0
+            0x005672b3, // and t0, a2, t0
+            0x0056e6b3, // or a3, a3, t0
+        ], select_mask(A3, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_mask_rd_cond() {
+        assert_eq!(vec![
+            0x00b032b3, // snez t0, a1
+            0x405002b3, // neg t0, t0
+            0x005675b3, // and a1, a2, t0
+            0xfff2c293, // not t0, t0
+            0x0056f2b3, // and t0, a3, t0
+            0x0055e5b3, // or a1, a1, t0
+        ], select_mask(A1, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_mask_same() {
+        assert_eq!(vec![
+            0x00060513, // mv a0, a2
+        ], select_mask(A0, A1, A2, A2, T0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_mask_tmp() {
+        select_mask(A0, A1, A2, A3, A2);
+    }
+}
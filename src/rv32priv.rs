@@ -0,0 +1,67 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_dret() => dret;
+        emit_mret() => mret;
+        emit_sfence_inval_ir() => sfence_inval_ir;
+        emit_sfence_vma(vaddr: Option<Register>, asid: Option<Register>) => sfence_vma;
+        emit_sfence_w_inval() => sfence_w_inval;
+        emit_sinval_vma(vaddr: Option<Register>, asid: Option<Register>) => sinval_vma;
+        emit_sret() => sret;
+        emit_wfi() => wfi;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn mret() -> u32 {
+    RType { opcode: System, funct3: 0b000, funct7: 0b0011000, rd: Register::Zero, rs1: Register::Zero, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn sret() -> u32 {
+    RType { opcode: System, funct3: 0b000, funct7: 0b0001000, rd: Register::Zero, rs1: Register::Zero, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn dret() -> u32 {
+    RType { opcode: System, funct3: 0b000, funct7: 0b0111101, rd: Register::Zero, rs1: Register::Zero, rs2: 0b10010_u8 }.encode()
+}
+
+#[inline]
+pub fn wfi() -> u32 {
+    RType { opcode: System, funct3: 0b000, funct7: 0b0001000, rd: Register::Zero, rs1: Register::Zero, rs2: 0b00101_u8 }.encode()
+}
+
+#[inline]
+pub fn sfence_vma(vaddr: Option<Register>, asid: Option<Register>) -> u32 {
+    let rs1 = vaddr.unwrap_or(Register::Zero);
+    let rs2 = asid.unwrap_or(Register::Zero);
+    RType { opcode: System, funct3: 0b000, funct7: 0b0001001, rd: Register::Zero, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sinval_vma(vaddr: Option<Register>, asid: Option<Register>) -> u32 {
+    let rs1 = vaddr.unwrap_or(Register::Zero);
+    let rs2 = asid.unwrap_or(Register::Zero);
+    RType { opcode: System, funct3: 0b000, funct7: 0b0001011, rd: Register::Zero, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sfence_w_inval() -> u32 {
+    RType { opcode: System, funct3: 0b000, funct7: 0b0001100, rd: Register::Zero, rs1: Register::Zero, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn sfence_inval_ir() -> u32 {
+    RType { opcode: System, funct3: 0b000, funct7: 0b0001100, rd: Register::Zero, rs1: Register::Zero, rs2: 0b00001_u8 }.encode()
+}
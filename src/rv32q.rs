@@ -0,0 +1,391 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32i::Emit as _;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flq_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        self.emit_branch(
+            label,
+            FixupKind::Load,
+            |e, offset| {
+                let (upper, lower) = to_i20_i12_imm_pair(offset as i32);
+                e.emit_auipc(rt, upper)?;
+                e.emit_flq(rd, rt, lower)
+            },
+        )
+    }
+
+    forward! {
+        emit_fabs_q(rd: FRegister, rs: FRegister) => fabs_q;
+        emit_fadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fadd_q;
+        emit_fclass_q(rd: Register, rs: FRegister) => fclass_q;
+        emit_fcvt_d_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => fcvt_d_q;
+        emit_fcvt_q_d(rd: FRegister, rs: FRegister) => fcvt_q_d;
+        emit_fcvt_q_s(rd: FRegister, rs: FRegister) => fcvt_q_s;
+        emit_fcvt_q_w(rd: FRegister, rs: Register) => fcvt_q_w;
+        emit_fcvt_q_wu(rd: FRegister, rs: Register) => fcvt_q_wu;
+        emit_fcvt_s_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => fcvt_s_q;
+        emit_fcvt_w_q(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_w_q;
+        emit_fcvt_wu_q(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_wu_q;
+        emit_fdiv_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fdiv_q;
+        emit_feq_q(rd: Register, rs1: FRegister, rs2: FRegister) => feq_q;
+        emit_fle_q(rd: Register, rs1: FRegister, rs2: FRegister) => fle_q;
+        emit_flq(rd: FRegister, base: Register, offset: i16) => flq;
+        emit_flt_q(rd: Register, rs1: FRegister, rs2: FRegister) => flt_q;
+        emit_fmadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmadd_q;
+        emit_fmax_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmax_q;
+        emit_fmin_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmin_q;
+        emit_fmsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmsub_q;
+        emit_fmul_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fmul_q;
+        emit_fmv_q(rd: FRegister, rs: FRegister) => fmv_q;
+        emit_fneg_q(rd: FRegister, rs: FRegister) => fneg_q;
+        emit_fnmadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmadd_q;
+        emit_fnmsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmsub_q;
+        emit_fsgnj_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnj_q;
+        emit_fsgnjn_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjn_q;
+        emit_fsgnjx_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjx_q;
+        emit_fsq(rs: FRegister, base: Register, offset: i16) => fsq;
+        emit_fsqrt_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => fsqrt_q;
+        emit_fsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fsub_q;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+#[inline]
+pub fn flq(rd: FRegister, base: Register, offset: i16) -> u32 {
+    IType { opcode: LoadFp, funct3: 0b100, rd, rs: base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fsq(rs: FRegister, base: Register, offset: i16) -> u32 {
+    SType { opcode: StoreFp, funct3: 0b100, rs, base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fmadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Madd, funct2: 0b11, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fmsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Msub, funct2: 0b11, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmsub, funct2: 0b11, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmadd, funct2: 0b11, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmul_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fdiv_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsqrt_q(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0101111, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fsgnj_q(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjn_q(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjx_q(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmin_q(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmax_q(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fcvt_s_q(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100000, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_q_s(rd: FRegister, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0100011, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_d_q(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100001, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_q_d(rd: FRegister, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0100011, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn feq_q(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b1010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn flt_q(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fle_q(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fclass_q(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1110011, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_w_q(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100011, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_wu_q(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100011, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_q_w(rd: FRegister, rs: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1101011, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_q_wu(rd: FRegister, rs: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1101011, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_q(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnj_q(rd, rs, rs)
+}
+
+#[inline]
+pub fn fneg_q(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjn_q(rd, rs, rs)
+}
+
+#[inline]
+pub fn fabs_q(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjx_q(rd, rs, rs)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+    use crate::types::RoundingMode::*;
+
+    #[test]
+    fn test_fabs_q() {
+        assert_eq!(0x26b5a553, fabs_q(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fadd_q() {
+        assert_eq!(0x06c58553, fadd_q(Fa0, Fa1, Fa2, Rne));
+    }
+
+    #[test]
+    fn test_fclass_q() {
+        assert_eq!(0xe60b17d3, fclass_q(A5, Fs6));
+    }
+
+    #[test]
+    fn test_fcvt_d_q() {
+        assert_eq!(0x423170d3, fcvt_d_q(Ft1, Ft2, Dyn));
+    }
+
+    #[test]
+    fn test_fcvt_q_d() {
+        assert_eq!(0x461100d3, fcvt_q_d(Ft1, Ft2));
+    }
+
+    #[test]
+    fn test_fcvt_q_s() {
+        assert_eq!(0x46058553, fcvt_q_s(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fcvt_q_w() {
+        assert_eq!(0xd6050553, fcvt_q_w(Fa0, A0));
+    }
+
+    #[test]
+    fn test_fcvt_q_wu() {
+        assert_eq!(0xd61f8053, fcvt_q_wu(Ft0, T6));
+    }
+
+    #[test]
+    fn test_fcvt_s_q() {
+        assert_eq!(0x40359553, fcvt_s_q(Fa0, Fa1, Rtz));
+    }
+
+    #[test]
+    fn test_fcvt_w_q() {
+        assert_eq!(0xc6051553, fcvt_w_q(A0, Fa0, Rtz));
+    }
+
+    #[test]
+    fn test_fcvt_wu_q() {
+        assert_eq!(0xc61072d3, fcvt_wu_q(T0, Ft0, Dyn));
+    }
+
+    #[test]
+    fn test_fdiv_q() {
+        assert_eq!(0x1ea4f453, fdiv_q(Fs0, Fs1, Fa0, Dyn));
+    }
+
+    #[test]
+    fn test_feq_q() {
+        assert_eq!(0xa6b52553, feq_q(A0, Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fle_q() {
+        assert_eq!(0xa71f8dd3, fle_q(S11, Ft11, Fa7));
+    }
+
+    #[test]
+    fn test_flq() {
+        assert_eq!(0xff814507, flq(Fa0, Sp, -8));
+    }
+
+    #[test]
+    fn test_flt_q() {
+        assert_eq!(0xa6909353, flt_q(T1, Ft1, Fs1));
+    }
+
+    #[test]
+    fn test_fmadd_q() {
+        assert_eq!(0x6ec59543, fmadd_q(Fa0, Fa1, Fa2, Fa3, Rtz));
+    }
+
+    #[test]
+    fn test_fmax_q() {
+        assert_eq!(0x2e3110d3, fmax_q(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fmin_q() {
+        assert_eq!(0x2e3100d3, fmin_q(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fmsub_q() {
+        assert_eq!(0x1e20f047, fmsub_q(Ft0, Ft1, Ft2, Ft3, Dyn));
+    }
+
+    #[test]
+    fn test_fmul_q() {
+        assert_eq!(0x17b8a053, fmul_q(Ft0, Fa7, Fs11, Rdn));
+    }
+
+    #[test]
+    fn test_fmv_q() {
+        assert_eq!(0x26b58553, fmv_q(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fneg_q() {
+        assert_eq!(0x26b59553, fneg_q(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fnmadd_q() {
+        assert_eq!(0xffeece4f, fnmadd_q(Ft8, Ft9, Ft10, Ft11, Rmm));
+    }
+
+    #[test]
+    fn test_fnmsub_q() {
+        assert_eq!(0xaf49894b, fnmsub_q(Fs2, Fs3, Fs4, Fs5, Rne));
+    }
+
+    #[test]
+    fn test_fsgnj_q() {
+        assert_eq!(0x26c58553, fsgnj_q(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fsgnjn_q() {
+        assert_eq!(0x26c59553, fsgnjn_q(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fsgnjx_q() {
+        assert_eq!(0x26c5a553, fsgnjx_q(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fsq() {
+        assert_eq!(0x7e954c27, fsq(Fs1, A0, 2040));
+    }
+
+    #[test]
+    fn test_fsqrt_q() {
+        assert_eq!(0x5e0817d3, fsqrt_q(Fa5, Fa6, Rtz));
+    }
+
+    #[test]
+    fn test_fsub_q() {
+        assert_eq!(0x0ec5b553, fsub_q(Fa0, Fa1, Fa2, Rup));
+    }
+}
@@ -0,0 +1,3709 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32i::Emit as _;
+
+use crate::{rv32i, rv32zicsr};
+
+
+#[allow(clippy::too_many_arguments)]
+pub trait Emit: EmitSlice {
+    /// `head` must be bound right before this call.
+    fn emit_strip_mine<Label, F>(
+        &mut self,
+        head: &mut Label,
+        n: Register,
+        vl: Register,
+        vtype: VType,
+        pointers: &[Register],
+        body: F,
+    ) -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>,
+          F: FnOnce(&mut Self) -> Result<(), Self::Error>
+    {
+        self.emit_vsetvli(vl, n, vtype)?;
+        body(self)?;
+        emit_words(self, &advance(n, vl, vtype.sew, pointers))?;
+        self.emit_bnez_label(n, head)
+    }
+
+    fn emit_vmemcpy(
+        &mut self,
+        dst: Register,
+        src: Register,
+        n: Register,
+        vl: Register,
+        v: VRegister,
+    ) -> Result<(), Self::Error> {
+        emit_words(self, &vmemcpy(dst, src, n, vl, v))
+    }
+
+    fn emit_vmemset(
+        &mut self,
+        dst: Register,
+        value: Register,
+        n: Register,
+        vl: Register,
+        v: VRegister,
+    ) -> Result<(), Self::Error> {
+        emit_words(self, &vmemset(dst, value, n, vl, v))
+    }
+
+    fn emit_vstrlen(
+        &mut self,
+        s: Register,
+        len: Register,
+        vl: Register,
+        index: Register,
+        v: VRegister,
+    ) -> Result<(), Self::Error> {
+        emit_words(self, &vstrlen(s, len, vl, index, v))
+    }
+
+    fn emit_vfdot_s(
+        &mut self,
+        result: FRegister,
+        x: Register,
+        y: Register,
+        n: Register,
+        vl: Register,
+        acc: VRegister,
+        vx: VRegister,
+        vy: VRegister,
+    ) -> Result<(), Self::Error> {
+        emit_words(self, &vfdot_s(result, x, y, n, vl, acc, vx, vy))
+    }
+
+    forward! {
+        emit_vaadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vaadd_vv;
+        emit_vaadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vaadd_vx;
+        emit_vaaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vaaddu_vv;
+        emit_vaaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vaaddu_vx;
+        emit_vadc_vim(vd: VRegister, vs2: VRegister, imm: i8) => vadc_vim;
+        emit_vadc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vadc_vvm;
+        emit_vadc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => vadc_vxm;
+        emit_vadd_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vadd_vi;
+        emit_vadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vadd_vv;
+        emit_vadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vadd_vx;
+        emit_vand_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vand_vi;
+        emit_vand_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vand_vv;
+        emit_vand_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vand_vx;
+        emit_vasub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vasub_vv;
+        emit_vasub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vasub_vx;
+        emit_vasubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vasubu_vv;
+        emit_vasubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vasubu_vx;
+        emit_vcompress_vm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vcompress_vm;
+        emit_vcpop_m(rd: Register, vs2: VRegister, vm: VMask) => vcpop_m;
+        emit_vdiv_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vdiv_vv;
+        emit_vdiv_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vdiv_vx;
+        emit_vdivu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vdivu_vv;
+        emit_vdivu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vdivu_vx;
+        emit_vfabs_v(vd: VRegister, vs: VRegister, vm: VMask) => vfabs_v;
+        emit_vfadd_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfadd_vf;
+        emit_vfadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfadd_vv;
+        emit_vfclass_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfclass_v;
+        emit_vfcvt_f_x_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfcvt_f_x_v;
+        emit_vfcvt_f_xu_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfcvt_f_xu_v;
+        emit_vfcvt_rtz_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfcvt_rtz_x_f_v;
+        emit_vfcvt_rtz_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfcvt_rtz_xu_f_v;
+        emit_vfcvt_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfcvt_x_f_v;
+        emit_vfcvt_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfcvt_xu_f_v;
+        emit_vfdiv_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfdiv_vf;
+        emit_vfdiv_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfdiv_vv;
+        emit_vfirst_m(rd: Register, vs2: VRegister, vm: VMask) => vfirst_m;
+        emit_vfmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfmacc_vf;
+        emit_vfmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfmacc_vv;
+        emit_vfmadd_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfmadd_vf;
+        emit_vfmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfmadd_vv;
+        emit_vfmax_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfmax_vf;
+        emit_vfmax_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfmax_vv;
+        emit_vfmerge_vfm(vd: VRegister, vs2: VRegister, rs1: FRegister) => vfmerge_vfm;
+        emit_vfmin_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfmin_vf;
+        emit_vfmin_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfmin_vv;
+        emit_vfmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfmsac_vf;
+        emit_vfmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfmsac_vv;
+        emit_vfmsub_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfmsub_vf;
+        emit_vfmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfmsub_vv;
+        emit_vfmul_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfmul_vf;
+        emit_vfmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfmul_vv;
+        emit_vfmv_f_s(rd: FRegister, vs2: VRegister) => vfmv_f_s;
+        emit_vfmv_s_f(vd: VRegister, rs1: FRegister) => vfmv_s_f;
+        emit_vfmv_v_f(vd: VRegister, rs1: FRegister) => vfmv_v_f;
+        emit_vfncvt_f_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_f_f_w;
+        emit_vfncvt_f_x_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_f_x_w;
+        emit_vfncvt_f_xu_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_f_xu_w;
+        emit_vfncvt_rod_f_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_rod_f_f_w;
+        emit_vfncvt_rtz_x_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_rtz_x_f_w;
+        emit_vfncvt_rtz_xu_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_rtz_xu_f_w;
+        emit_vfncvt_x_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_x_f_w;
+        emit_vfncvt_xu_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => vfncvt_xu_f_w;
+        emit_vfneg_v(vd: VRegister, vs: VRegister, vm: VMask) => vfneg_v;
+        emit_vfnmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfnmacc_vf;
+        emit_vfnmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfnmacc_vv;
+        emit_vfnmadd_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfnmadd_vf;
+        emit_vfnmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfnmadd_vv;
+        emit_vfnmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfnmsac_vf;
+        emit_vfnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfnmsac_vv;
+        emit_vfnmsub_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfnmsub_vf;
+        emit_vfnmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfnmsub_vv;
+        emit_vfrdiv_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfrdiv_vf;
+        emit_vfrec7_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfrec7_v;
+        emit_vfredmax_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfredmax_vs;
+        emit_vfredmin_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfredmin_vs;
+        emit_vfredosum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfredosum_vs;
+        emit_vfredusum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfredusum_vs;
+        emit_vfrsqrt7_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfrsqrt7_v;
+        emit_vfrsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfrsub_vf;
+        emit_vfsgnj_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfsgnj_vf;
+        emit_vfsgnj_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfsgnj_vv;
+        emit_vfsgnjn_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfsgnjn_vf;
+        emit_vfsgnjn_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfsgnjn_vv;
+        emit_vfsgnjx_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfsgnjx_vf;
+        emit_vfsgnjx_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfsgnjx_vv;
+        emit_vfslide1down_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfslide1down_vf;
+        emit_vfslide1up_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfslide1up_vf;
+        emit_vfsqrt_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfsqrt_v;
+        emit_vfsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfsub_vf;
+        emit_vfsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfsub_vv;
+        emit_vfwadd_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfwadd_vf;
+        emit_vfwadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfwadd_vv;
+        emit_vfwadd_wf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfwadd_wf;
+        emit_vfwadd_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfwadd_wv;
+        emit_vfwcvt_f_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfwcvt_f_f_v;
+        emit_vfwcvt_f_x_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfwcvt_f_x_v;
+        emit_vfwcvt_f_xu_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfwcvt_f_xu_v;
+        emit_vfwcvt_rtz_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfwcvt_rtz_x_f_v;
+        emit_vfwcvt_rtz_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfwcvt_rtz_xu_f_v;
+        emit_vfwcvt_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfwcvt_x_f_v;
+        emit_vfwcvt_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => vfwcvt_xu_f_v;
+        emit_vfwmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfwmacc_vf;
+        emit_vfwmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfwmacc_vv;
+        emit_vfwmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfwmsac_vf;
+        emit_vfwmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfwmsac_vv;
+        emit_vfwmul_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfwmul_vf;
+        emit_vfwmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfwmul_vv;
+        emit_vfwnmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfwnmacc_vf;
+        emit_vfwnmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfwnmacc_vv;
+        emit_vfwnmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => vfwnmsac_vf;
+        emit_vfwnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vfwnmsac_vv;
+        emit_vfwredosum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfwredosum_vs;
+        emit_vfwredusum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfwredusum_vs;
+        emit_vfwsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfwsub_vf;
+        emit_vfwsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfwsub_vv;
+        emit_vfwsub_wf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vfwsub_wf;
+        emit_vfwsub_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vfwsub_wv;
+        emit_vid_v(vd: VRegister, vm: VMask) => vid_v;
+        emit_viota_m(vd: VRegister, vs2: VRegister, vm: VMask) => viota_m;
+        emit_vle_v(eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => vle_v;
+        emit_vleff_v(eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => vleff_v;
+        emit_vlm_v(vd: VRegister, base: Register) => vlm_v;
+        emit_vloxei_v(eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vloxei_v;
+        emit_vloxseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vloxseg_v;
+        emit_vlre_v(nf: u8, eew: Sew, vd: VRegister, base: Register) => vlre_v;
+        emit_vlse_v(eew: Sew, vd: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => vlse_v;
+        emit_vlseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => vlseg_v;
+        emit_vlsegff_v(nf: u8, eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => vlsegff_v;
+        emit_vlsseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => vlsseg_v;
+        emit_vluxei_v(eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vluxei_v;
+        emit_vluxseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vluxseg_v;
+        emit_vmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vmacc_vv;
+        emit_vmacc_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vmacc_vx;
+        emit_vmadc_vi(vd: VRegister, vs2: VRegister, imm: i8) => vmadc_vi;
+        emit_vmadc_vim(vd: VRegister, vs2: VRegister, imm: i8) => vmadc_vim;
+        emit_vmadc_vv(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmadc_vv;
+        emit_vmadc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmadc_vvm;
+        emit_vmadc_vx(vd: VRegister, vs2: VRegister, rs1: Register) => vmadc_vx;
+        emit_vmadc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => vmadc_vxm;
+        emit_vmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vmadd_vv;
+        emit_vmadd_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vmadd_vx;
+        emit_vmand_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmand_mm;
+        emit_vmandn_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmandn_mm;
+        emit_vmax_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmax_vv;
+        emit_vmax_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmax_vx;
+        emit_vmaxu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmaxu_vv;
+        emit_vmaxu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmaxu_vx;
+        emit_vmclr_m(vd: VRegister) => vmclr_m;
+        emit_vmerge_vim(vd: VRegister, vs2: VRegister, imm: i8) => vmerge_vim;
+        emit_vmerge_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmerge_vvm;
+        emit_vmerge_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => vmerge_vxm;
+        emit_vmfeq_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vmfeq_vf;
+        emit_vmfeq_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmfeq_vv;
+        emit_vmfge_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vmfge_vf;
+        emit_vmfgt_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vmfgt_vf;
+        emit_vmfle_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vmfle_vf;
+        emit_vmfle_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmfle_vv;
+        emit_vmflt_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vmflt_vf;
+        emit_vmflt_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmflt_vv;
+        emit_vmfne_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => vmfne_vf;
+        emit_vmfne_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmfne_vv;
+        emit_vmin_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmin_vv;
+        emit_vmin_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmin_vx;
+        emit_vminu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vminu_vv;
+        emit_vminu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vminu_vx;
+        emit_vmmv_m(vd: VRegister, vs: VRegister) => vmmv_m;
+        emit_vmnand_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmnand_mm;
+        emit_vmnor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmnor_mm;
+        emit_vmnot_m(vd: VRegister, vs: VRegister) => vmnot_m;
+        emit_vmor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmor_mm;
+        emit_vmorn_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmorn_mm;
+        emit_vmsbc_vv(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmsbc_vv;
+        emit_vmsbc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmsbc_vvm;
+        emit_vmsbc_vx(vd: VRegister, vs2: VRegister, rs1: Register) => vmsbc_vx;
+        emit_vmsbc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => vmsbc_vxm;
+        emit_vmsbf_m(vd: VRegister, vs2: VRegister, vm: VMask) => vmsbf_m;
+        emit_vmseq_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vmseq_vi;
+        emit_vmseq_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmseq_vv;
+        emit_vmseq_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmseq_vx;
+        emit_vmset_m(vd: VRegister) => vmset_m;
+        emit_vmsgt_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vmsgt_vi;
+        emit_vmsgt_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmsgt_vx;
+        emit_vmsgtu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vmsgtu_vi;
+        emit_vmsgtu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmsgtu_vx;
+        emit_vmsif_m(vd: VRegister, vs2: VRegister, vm: VMask) => vmsif_m;
+        emit_vmsle_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vmsle_vi;
+        emit_vmsle_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmsle_vv;
+        emit_vmsle_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmsle_vx;
+        emit_vmsleu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vmsleu_vi;
+        emit_vmsleu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmsleu_vv;
+        emit_vmsleu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmsleu_vx;
+        emit_vmslt_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmslt_vv;
+        emit_vmslt_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmslt_vx;
+        emit_vmsltu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmsltu_vv;
+        emit_vmsltu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmsltu_vx;
+        emit_vmsne_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vmsne_vi;
+        emit_vmsne_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmsne_vv;
+        emit_vmsne_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmsne_vx;
+        emit_vmsof_m(vd: VRegister, vs2: VRegister, vm: VMask) => vmsof_m;
+        emit_vmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmul_vv;
+        emit_vmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmul_vx;
+        emit_vmulh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmulh_vv;
+        emit_vmulh_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmulh_vx;
+        emit_vmulhsu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmulhsu_vv;
+        emit_vmulhsu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmulhsu_vx;
+        emit_vmulhu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vmulhu_vv;
+        emit_vmulhu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vmulhu_vx;
+        emit_vmv_s_x(vd: VRegister, rs1: Register) => vmv_s_x;
+        emit_vmv_v_i(vd: VRegister, imm: i8) => vmv_v_i;
+        emit_vmv_v_v(vd: VRegister, vs1: VRegister) => vmv_v_v;
+        emit_vmv_v_x(vd: VRegister, rs1: Register) => vmv_v_x;
+        emit_vmv_x_s(rd: Register, vs2: VRegister) => vmv_x_s;
+        emit_vmvr_v(nr: u8, vd: VRegister, vs2: VRegister) => vmvr_v;
+        emit_vmxnor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmxnor_mm;
+        emit_vmxor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vmxor_mm;
+        emit_vnclip_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vnclip_wi;
+        emit_vnclip_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vnclip_wv;
+        emit_vnclip_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vnclip_wx;
+        emit_vnclipu_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vnclipu_wi;
+        emit_vnclipu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vnclipu_wv;
+        emit_vnclipu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vnclipu_wx;
+        emit_vneg_v(vd: VRegister, vs2: VRegister, vm: VMask) => vneg_v;
+        emit_vnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vnmsac_vv;
+        emit_vnmsac_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vnmsac_vx;
+        emit_vnmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vnmsub_vv;
+        emit_vnmsub_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vnmsub_vx;
+        emit_vnot_v(vd: VRegister, vs2: VRegister, vm: VMask) => vnot_v;
+        emit_vnsra_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vnsra_wi;
+        emit_vnsra_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vnsra_wv;
+        emit_vnsra_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vnsra_wx;
+        emit_vnsrl_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vnsrl_wi;
+        emit_vnsrl_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vnsrl_wv;
+        emit_vnsrl_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vnsrl_wx;
+        emit_vor_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vor_vi;
+        emit_vor_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vor_vv;
+        emit_vor_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vor_vx;
+        emit_vredand_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredand_vs;
+        emit_vredmax_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredmax_vs;
+        emit_vredmaxu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredmaxu_vs;
+        emit_vredmin_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredmin_vs;
+        emit_vredminu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredminu_vs;
+        emit_vredor_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredor_vs;
+        emit_vredsum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredsum_vs;
+        emit_vredxor_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vredxor_vs;
+        emit_vrem_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vrem_vv;
+        emit_vrem_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vrem_vx;
+        emit_vremu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vremu_vv;
+        emit_vremu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vremu_vx;
+        emit_vrgather_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vrgather_vi;
+        emit_vrgather_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vrgather_vv;
+        emit_vrgather_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vrgather_vx;
+        emit_vrgatherei16_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vrgatherei16_vv;
+        emit_vrsub_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vrsub_vi;
+        emit_vrsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vrsub_vx;
+        emit_vsadd_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vsadd_vi;
+        emit_vsadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vsadd_vv;
+        emit_vsadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vsadd_vx;
+        emit_vsaddu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vsaddu_vi;
+        emit_vsaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vsaddu_vv;
+        emit_vsaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vsaddu_vx;
+        emit_vsbc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => vsbc_vvm;
+        emit_vsbc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => vsbc_vxm;
+        emit_vse_v(eew: Sew, vs: VRegister, base: Register, vm: VMask, vtype: VType) => vse_v;
+        emit_vsetivli(rd: Register, avl: u8, vtype: VType) => vsetivli;
+        emit_vsetvl(rd: Register, rs1: Register, rs2: Register) => vsetvl;
+        emit_vsetvli(rd: Register, rs: Register, vtype: VType) => vsetvli;
+        emit_vsext_vf2(vd: VRegister, vs2: VRegister, vm: VMask) => vsext_vf2;
+        emit_vsext_vf4(vd: VRegister, vs2: VRegister, vm: VMask) => vsext_vf4;
+        emit_vsext_vf8(vd: VRegister, vs2: VRegister, vm: VMask) => vsext_vf8;
+        emit_vslide1down_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vslide1down_vx;
+        emit_vslide1up_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vslide1up_vx;
+        emit_vslidedown_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vslidedown_vi;
+        emit_vslidedown_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vslidedown_vx;
+        emit_vslideup_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vslideup_vi;
+        emit_vslideup_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vslideup_vx;
+        emit_vsll_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vsll_vi;
+        emit_vsll_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vsll_vv;
+        emit_vsll_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vsll_vx;
+        emit_vsm_v(vs: VRegister, base: Register) => vsm_v;
+        emit_vsmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vsmul_vv;
+        emit_vsmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vsmul_vx;
+        emit_vsoxei_v(eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vsoxei_v;
+        emit_vsoxseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vsoxseg_v;
+        emit_vsr_v(nf: u8, vs: VRegister, base: Register) => vsr_v;
+        emit_vsra_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vsra_vi;
+        emit_vsra_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vsra_vv;
+        emit_vsra_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vsra_vx;
+        emit_vsrl_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vsrl_vi;
+        emit_vsrl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vsrl_vv;
+        emit_vsrl_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vsrl_vx;
+        emit_vsse_v(eew: Sew, vs: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => vsse_v;
+        emit_vsseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, vm: VMask, vtype: VType) => vsseg_v;
+        emit_vssra_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vssra_vi;
+        emit_vssra_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vssra_vv;
+        emit_vssra_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vssra_vx;
+        emit_vssrl_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vssrl_vi;
+        emit_vssrl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vssrl_vv;
+        emit_vssrl_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vssrl_vx;
+        emit_vssseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => vssseg_v;
+        emit_vssub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vssub_vv;
+        emit_vssub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vssub_vx;
+        emit_vssubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vssubu_vv;
+        emit_vssubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vssubu_vx;
+        emit_vsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vsub_vv;
+        emit_vsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vsub_vx;
+        emit_vsuxei_v(eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vsuxei_v;
+        emit_vsuxseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => vsuxseg_v;
+        emit_vwadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwadd_vv;
+        emit_vwadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwadd_vx;
+        emit_vwadd_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwadd_wv;
+        emit_vwadd_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwadd_wx;
+        emit_vwaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwaddu_vv;
+        emit_vwaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwaddu_vx;
+        emit_vwaddu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwaddu_wv;
+        emit_vwaddu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwaddu_wx;
+        emit_vwmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vwmacc_vv;
+        emit_vwmacc_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vwmacc_vx;
+        emit_vwmaccsu_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vwmaccsu_vv;
+        emit_vwmaccsu_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vwmaccsu_vx;
+        emit_vwmaccu_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => vwmaccu_vv;
+        emit_vwmaccu_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vwmaccu_vx;
+        emit_vwmaccus_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => vwmaccus_vx;
+        emit_vwmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwmul_vv;
+        emit_vwmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwmul_vx;
+        emit_vwmulsu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwmulsu_vv;
+        emit_vwmulsu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwmulsu_vx;
+        emit_vwmulu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwmulu_vv;
+        emit_vwmulu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwmulu_vx;
+        emit_vwredsum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwredsum_vs;
+        emit_vwredsumu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwredsumu_vs;
+        emit_vwsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwsub_vv;
+        emit_vwsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwsub_vx;
+        emit_vwsub_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwsub_wv;
+        emit_vwsub_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwsub_wx;
+        emit_vwsubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwsubu_vv;
+        emit_vwsubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwsubu_vx;
+        emit_vwsubu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwsubu_wv;
+        emit_vwsubu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwsubu_wx;
+        emit_vxor_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => vxor_vi;
+        emit_vxor_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vxor_vv;
+        emit_vxor_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vxor_vx;
+        emit_vzext_vf2(vd: VRegister, vs2: VRegister, vm: VMask) => vzext_vf2;
+        emit_vzext_vf4(vd: VRegister, vs2: VRegister, vm: VMask) => vzext_vf4;
+        emit_vzext_vf8(vd: VRegister, vs2: VRegister, vm: VMask) => vzext_vf8;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+fn emit_words<E>(e: &mut E, words: &[u32]) -> Result<(), E::Error>
+    where E: EmitSlice + ?Sized
+{
+    for word in words {
+        e.emit_slice(&word.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn advance(n: Register, vl: Register, sew: Sew, pointers: &[Register]) -> Vec<u32> {
+    let mut code = vec![rv32i::sub(n, n, vl)];
+    let shift = sew.log2() - 3;
+    if shift > 0 {
+        code.push(rv32i::slli(vl, vl, shift as u8));
+    }
+    for &pointer in pointers {
+        code.push(rv32i::add(pointer, pointer, vl));
+    }
+    code
+}
+
+fn strip_mine(n: Register, vl: Register, vtype: VType, pointers: &[Register], body: &[u32])
+    -> Vec<u32>
+{
+    let mut code = vec![vsetvli(vl, n, vtype)];
+    code.extend_from_slice(body);
+    code.extend(advance(n, vl, vtype.sew, pointers));
+    code.push(rv32i::bnez(n, -4 * code.len() as i16));
+    code
+}
+
+#[inline]
+fn byte_vtype() -> VType {
+    VType::new(Sew::E8, Lmul::M8)
+        .tail(TailPolicy::Agnostic)
+        .mask(MaskPolicy::Agnostic)
+}
+
+fn vmemcpy(dst: Register, src: Register, n: Register, vl: Register, v: VRegister) -> Vec<u32> {
+    let vtype = byte_vtype();
+    strip_mine(n, vl, vtype, &[dst, src], &[
+        vle_v(Sew::E8, v, src, VMask::Unmasked, vtype),
+        vse_v(Sew::E8, v, dst, VMask::Unmasked, vtype),
+    ])
+}
+
+fn vmemset(dst: Register, value: Register, n: Register, vl: Register, v: VRegister) -> Vec<u32> {
+    let vtype = byte_vtype();
+    strip_mine(n, vl, vtype, &[dst], &[
+        vmv_v_x(v, value),
+        vse_v(Sew::E8, v, dst, VMask::Unmasked, vtype),
+    ])
+}
+
+fn vstrlen(s: Register, len: Register, vl: Register, index: Register, v: VRegister) -> Vec<u32> {
+    assert!(v != VRegister::V0);
+    let vtype = byte_vtype();
+    let mut code = vec![
+        vsetvli(vl, Register::Zero, vtype),
+        vleff_v(Sew::E8, v, s, VMask::Unmasked, vtype),
+        rv32zicsr::csrr(vl, Csr::VL),
+        vmseq_vi(VRegister::V0, v, 0, VMask::Unmasked),
+        vfirst_m(index, VRegister::V0, VMask::Unmasked),
+        rv32i::add(s, s, vl),
+        rv32i::add(len, len, vl),
+    ];
+    code.push(rv32i::bltz(index, -4 * code.len() as i16));
+    code.push(rv32i::sub(len, len, vl));
+    code.push(rv32i::add(len, len, index));
+    code
+}
+
+#[allow(clippy::too_many_arguments)]
+fn vfdot_s(
+    result: FRegister,
+    x: Register,
+    y: Register,
+    n: Register,
+    vl: Register,
+    acc: VRegister,
+    vx: VRegister,
+    vy: VRegister,
+) -> Vec<u32> {
+    check_group(acc, Lmul::M4, 1);
+    check_group(vx, Lmul::M4, 1);
+    check_group(vy, Lmul::M4, 1);
+    assert!(acc != vx && acc != vy && vx != vy);
+    let vlmax = VType::new(Sew::E32, Lmul::M4)
+        .tail(TailPolicy::Agnostic)
+        .mask(MaskPolicy::Agnostic);
+    // The last strip may be short, so the tail of `acc` must keep its partial sums.
+    let vtype = VType::new(Sew::E32, Lmul::M4);
+    let mut code = vec![
+        vsetvli(vl, Register::Zero, vlmax),
+        vmv_v_i(acc, 0),
+    ];
+    code.extend(strip_mine(n, vl, vtype, &[x, y], &[
+        vle_v(Sew::E32, vx, x, VMask::Unmasked, vtype),
+        vle_v(Sew::E32, vy, y, VMask::Unmasked, vtype),
+        vfmacc_vv(acc, vx, vy, VMask::Unmasked),
+    ]));
+    code.extend([
+        vsetvli(vl, Register::Zero, vlmax),
+        vfmv_s_f(vx, result),
+        vfredusum_vs(vx, acc, vx, VMask::Unmasked),
+        vfmv_f_s(result, vx),
+    ]);
+    code
+}
+
+#[inline]
+fn width(eew: Sew) -> u8 {
+    match eew {
+        Sew::E8 => 0b000,
+        Sew::E16 => 0b101,
+        Sew::E32 => 0b110,
+        Sew::E64 => 0b111,
+    }
+}
+
+#[inline]
+fn check_group(reg: VRegister, emul: Lmul, nf: u8) {
+    let registers = emul.registers();
+    let count = registers * nf as usize;
+    assert!((1..=8).contains(&nf));
+    assert!(reg as usize & (registers - 1) == 0 && count <= 8 && reg as usize + count <= 32);
+}
+
+#[inline]
+fn check_mask(vd: VRegister, vm: VMask) {
+    assert!(vm == VMask::Unmasked || vd != VRegister::V0);
+}
+
+#[inline]
+fn unit_stride(opcode: Opcode, nf: u8, lumop: u8, eew: Sew, vd: VRegister, base: Register, vm: VMask)
+    -> u32
+{
+    VMemType { opcode, nf: nf - 1, mop: 0b00, vm, rs2: lumop, rs1: base, width: width(eew), vd }
+        .encode()
+}
+
+#[inline]
+fn strided(opcode: Opcode, nf: u8, eew: Sew, vd: VRegister, base: Register, stride: Register, vm: VMask)
+    -> u32
+{
+    VMemType { opcode, nf: nf - 1, mop: 0b10, vm, rs2: stride, rs1: base, width: width(eew), vd }
+        .encode()
+}
+
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn indexed(
+    opcode: Opcode, mop: u8, nf: u8, eew: Sew,
+    vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType,
+) -> u32 {
+    check_group(vd, vtype.lmul, nf);
+    check_group(index, vtype.emul(eew).unwrap(), 1);
+    VMemType { opcode, nf: nf - 1, mop, vm, rs2: index, rs1: base, width: width(eew), vd }
+        .encode()
+}
+
+
+#[inline]
+pub fn vsetvli(rd: Register, rs: Register, vtype: VType) -> u32 {
+    IType { opcode: OpV, funct3: 0b111, rd, rs, imm12: vtype.bits() as i16 }.encode()
+}
+
+#[inline]
+pub fn vsetivli(rd: Register, avl: u8, vtype: VType) -> u32 {
+    assert!(avl < 32);
+    IType { opcode: OpV, funct3: 0b111, rd, rs: avl, imm12: (0xfc00 | vtype.bits()) as i16 }.encode()
+}
+
+#[inline]
+pub fn vsetvl(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: OpV, funct3: 0b111, funct7: 0b1000000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn vle_v(eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) -> u32 {
+    vlseg_v(1, eew, vd, base, vm, vtype)
+}
+
+#[inline]
+pub fn vleff_v(eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) -> u32 {
+    vlsegff_v(1, eew, vd, base, vm, vtype)
+}
+
+#[inline]
+pub fn vse_v(eew: Sew, vs: VRegister, base: Register, vm: VMask, vtype: VType) -> u32 {
+    vsseg_v(1, eew, vs, base, vm, vtype)
+}
+
+#[inline]
+pub fn vlm_v(vd: VRegister, base: Register) -> u32 {
+    unit_stride(LoadFp, 1, 0b01011, Sew::E8, vd, base, VMask::Unmasked)
+}
+
+#[inline]
+pub fn vsm_v(vs: VRegister, base: Register) -> u32 {
+    unit_stride(StoreFp, 1, 0b01011, Sew::E8, vs, base, VMask::Unmasked)
+}
+
+#[inline]
+pub fn vlse_v(eew: Sew, vd: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType)
+    -> u32
+{
+    vlsseg_v(1, eew, vd, base, stride, vm, vtype)
+}
+
+#[inline]
+pub fn vsse_v(eew: Sew, vs: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType)
+    -> u32
+{
+    vssseg_v(1, eew, vs, base, stride, vm, vtype)
+}
+
+#[inline]
+pub fn vluxei_v(eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType)
+    -> u32
+{
+    vluxseg_v(1, eew, vd, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vloxei_v(eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType)
+    -> u32
+{
+    vloxseg_v(1, eew, vd, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vsuxei_v(eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType)
+    -> u32
+{
+    vsuxseg_v(1, eew, vs, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vsoxei_v(eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType)
+    -> u32
+{
+    vsoxseg_v(1, eew, vs, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vlseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) -> u32 {
+    check_group(vd, vtype.emul(eew).unwrap(), nf);
+    check_mask(vd, vm);
+    unit_stride(LoadFp, nf, 0b00000, eew, vd, base, vm)
+}
+
+#[inline]
+pub fn vlsegff_v(nf: u8, eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) -> u32 {
+    check_group(vd, vtype.emul(eew).unwrap(), nf);
+    check_mask(vd, vm);
+    unit_stride(LoadFp, nf, 0b10000, eew, vd, base, vm)
+}
+
+#[inline]
+pub fn vsseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, vm: VMask, vtype: VType) -> u32 {
+    check_group(vs, vtype.emul(eew).unwrap(), nf);
+    unit_stride(StoreFp, nf, 0b00000, eew, vs, base, vm)
+}
+
+#[inline]
+pub fn vlsseg_v(
+    nf: u8, eew: Sew, vd: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType,
+) -> u32 {
+    check_group(vd, vtype.emul(eew).unwrap(), nf);
+    check_mask(vd, vm);
+    strided(LoadFp, nf, eew, vd, base, stride, vm)
+}
+
+#[inline]
+pub fn vssseg_v(
+    nf: u8, eew: Sew, vs: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType,
+) -> u32 {
+    check_group(vs, vtype.emul(eew).unwrap(), nf);
+    strided(StoreFp, nf, eew, vs, base, stride, vm)
+}
+
+#[inline]
+pub fn vluxseg_v(
+    nf: u8, eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType,
+) -> u32 {
+    check_mask(vd, vm);
+    indexed(LoadFp, 0b01, nf, eew, vd, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vloxseg_v(
+    nf: u8, eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType,
+) -> u32 {
+    check_mask(vd, vm);
+    indexed(LoadFp, 0b11, nf, eew, vd, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vsuxseg_v(
+    nf: u8, eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType,
+) -> u32 {
+    indexed(StoreFp, 0b01, nf, eew, vs, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vsoxseg_v(
+    nf: u8, eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType,
+) -> u32 {
+    indexed(StoreFp, 0b11, nf, eew, vs, base, index, vm, vtype)
+}
+
+#[inline]
+pub fn vlre_v(nf: u8, eew: Sew, vd: VRegister, base: Register) -> u32 {
+    assert!(nf.is_power_of_two());
+    check_group(vd, Lmul::from_log2(nf.trailing_zeros() as i8).unwrap(), 1);
+    unit_stride(LoadFp, nf, 0b01000, eew, vd, base, VMask::Unmasked)
+}
+
+#[inline]
+pub fn vsr_v(nf: u8, vs: VRegister, base: Register) -> u32 {
+    assert!(nf.is_power_of_two());
+    check_group(vs, Lmul::from_log2(nf.trailing_zeros() as i8).unwrap(), 1);
+    unit_stride(StoreFp, nf, 0b01000, Sew::E8, vs, base, VMask::Unmasked)
+}
+
+#[inline]
+pub fn vadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000000, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000000, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vadd_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b000000, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000010, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000010, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vrsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000011, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vrsub_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b000011, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vminu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000100, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vminu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000100, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmin_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000101, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmin_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000101, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmaxu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000110, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmaxu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000110, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmax_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000111, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmax_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000111, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vand_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001001, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vand_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001001, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vand_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b001001, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vor_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001010, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vor_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001010, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vor_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b001010, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vxor_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001011, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vxor_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001011, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vxor_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b001011, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vrgather_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001100, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vrgather_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001100, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vrgather_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b001100, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vslideup_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001110, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vslideup_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b001110, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vslidedown_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001111, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vslidedown_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b001111, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vmseq_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011000, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmseq_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011000, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmseq_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b011000, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmsne_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011001, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmsne_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011001, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsne_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b011001, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmsltu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011010, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmsltu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011010, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmslt_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011011, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmslt_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011011, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsleu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011100, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmsleu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011100, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsleu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b011100, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmsle_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011101, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmsle_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011101, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsle_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b011101, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmsgtu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011110, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsgtu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b011110, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmsgt_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011111, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsgt_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b011111, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vsaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100000, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100000, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vsaddu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b100000, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vsadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100001, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100001, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vsadd_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b100001, funct3: 0b011, vm, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vssubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100010, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vssubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100010, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vssub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100011, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vssub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100011, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vsll_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100101, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsll_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100101, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vsll_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b100101, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vsmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100111, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100111, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vsrl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101000, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsrl_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101000, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vsrl_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101000, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vsra_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101001, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsra_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101001, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vsra_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101001, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vssrl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101010, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vssrl_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101010, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vssrl_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101010, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vssra_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101011, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vssra_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101011, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vssra_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101011, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vrgatherei16_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001110, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vnsrl_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101100, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vnsrl_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101100, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vnsrl_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101100, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vnsra_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101101, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vnsra_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101101, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vnsra_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101101, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vnclipu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101110, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vnclipu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101110, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vnclipu_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101110, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vnclip_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101111, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vnclip_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101111, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vnclip_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b101111, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+#[inline]
+pub fn vwredsumu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110000, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwredsum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110001, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vadc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b000, vm: VMask::Masked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vadc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b100, vm: VMask::Masked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vadc_vim(vd: VRegister, vs2: VRegister, imm: i8) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b010000, funct3: 0b011, vm: VMask::Masked, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmadc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010001, funct3: 0b000, vm: VMask::Masked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmadc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010001, funct3: 0b100, vm: VMask::Masked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmadc_vim(vd: VRegister, vs2: VRegister, imm: i8) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b010001, funct3: 0b011, vm: VMask::Masked, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmadc_vv(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010001, funct3: 0b000, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmadc_vx(vd: VRegister, vs2: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010001, funct3: 0b100, vm: VMask::Unmasked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmadc_vi(vd: VRegister, vs2: VRegister, imm: i8) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b010001, funct3: 0b011, vm: VMask::Unmasked, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vsbc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b000, vm: VMask::Masked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vsbc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b100, vm: VMask::Masked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsbc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b000, vm: VMask::Masked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmsbc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b100, vm: VMask::Masked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmsbc_vv(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b000, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmsbc_vx(vd: VRegister, vs2: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b100, vm: VMask::Unmasked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmerge_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010111, funct3: 0b000, vm: VMask::Masked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmerge_vxm(vd: VRegister, vs2: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010111, funct3: 0b100, vm: VMask::Masked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmerge_vim(vd: VRegister, vs2: VRegister, imm: i8) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b010111, funct3: 0b011, vm: VMask::Masked, vd, vs2, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmv_v_v(vd: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010111, funct3: 0b000, vm: VMask::Unmasked, vd, vs2: VRegister::V0, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmv_v_x(vd: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010111, funct3: 0b100, vm: VMask::Unmasked, vd, vs2: VRegister::V0, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmv_v_i(vd: VRegister, imm: i8) -> u32 {
+    assert!(is_signed_nbit_integer(5, imm));
+    VArithType { funct6: 0b010111, funct3: 0b011, vm: VMask::Unmasked, vd, vs2: VRegister::V0, rs1: imm as u8 }.encode()
+}
+
+#[inline]
+pub fn vmvr_v(nr: u8, vd: VRegister, vs2: VRegister) -> u32 {
+    assert!(nr.is_power_of_two() && nr <= 8);
+    assert!(vd as u8 & (nr - 1) == 0 && vs2 as u8 & (nr - 1) == 0);
+    VArithType { funct6: 0b100111, funct3: 0b011, vm: VMask::Unmasked, vd, vs2, rs1: nr - 1 }.encode()
+}
+
+#[inline]
+pub fn vredsum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000000, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vredand_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000001, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vredor_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000010, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vredxor_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000011, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vredminu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000100, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vredmin_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000101, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vredmaxu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000110, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vredmax_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000111, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vaaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001000, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vaaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001000, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vaadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001001, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vaadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001001, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vasubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001010, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vasubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001010, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vasub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001011, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vasub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001011, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vslide1up_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001110, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vslide1down_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001111, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vdivu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100000, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vdivu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100000, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vdiv_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100001, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vdiv_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100001, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vremu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100010, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vremu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100010, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vrem_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100011, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vrem_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100011, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmulhu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100100, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmulhu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100100, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100101, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100101, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmulhsu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100110, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmulhsu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100110, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmulh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100111, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmulh_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100111, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110000, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110000, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110001, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110001, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwsubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110010, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwsubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110010, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110011, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110011, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwmulu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111000, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwmulu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111000, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwmulsu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111010, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwmulsu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111010, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111011, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111011, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwaddu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110100, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwaddu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110100, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwadd_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110101, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwadd_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110101, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwsubu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110110, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwsubu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110110, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwsub_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110111, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwsub_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110111, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101001, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmadd_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101001, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vnmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101011, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vnmsub_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101011, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101101, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmacc_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101101, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101111, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vnmsac_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101111, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwmaccu_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111100, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwmaccu_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111100, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111101, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwmacc_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111101, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwmaccus_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111110, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwmaccsu_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111111, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwmaccsu_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111111, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmandn_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmand_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011010, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmxor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011011, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmorn_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011100, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmnand_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011101, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmnor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011110, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmxnor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b011111, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vcompress_vm(vd: VRegister, vs2: VRegister, vs1: VRegister) -> u32 {
+    VArithType { funct6: 0b010111, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmv_x_s(rd: Register, vs2: VRegister) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b010, vm: VMask::Unmasked, vd: rd, vs2, rs1: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn vcpop_m(rd: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b010, vm, vd: rd, vs2, rs1: 0b10000_u8 }.encode()
+}
+
+#[inline]
+pub fn vfirst_m(rd: Register, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b010, vm, vd: rd, vs2, rs1: 0b10001_u8 }.encode()
+}
+
+#[inline]
+pub fn vmv_s_x(vd: VRegister, rs1: Register) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b110, vm: VMask::Unmasked, vd, vs2: VRegister::V0, rs1 }.encode()
+}
+
+#[inline]
+pub fn vzext_vf8(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn vsext_vf8(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn vzext_vf4(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b00100_u8 }.encode()
+}
+
+#[inline]
+pub fn vsext_vf4(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b00101_u8 }.encode()
+}
+
+#[inline]
+pub fn vzext_vf2(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b00110_u8 }.encode()
+}
+
+#[inline]
+pub fn vsext_vf2(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b00111_u8 }.encode()
+}
+
+#[inline]
+pub fn vmsbf_m(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010100, funct3: 0b010, vm, vd, vs2, rs1: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn vmsof_m(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010100, funct3: 0b010, vm, vd, vs2, rs1: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn vmsif_m(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010100, funct3: 0b010, vm, vd, vs2, rs1: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn viota_m(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010100, funct3: 0b010, vm, vd, vs2, rs1: 0b10000_u8 }.encode()
+}
+
+#[inline]
+pub fn vid_v(vd: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010100, funct3: 0b010, vm, vd, vs2: VRegister::V0, rs1: 0b10001_u8 }.encode()
+}
+
+#[inline]
+pub fn vnot_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    vxor_vi(vd, vs2, -1, vm)
+}
+
+#[inline]
+pub fn vneg_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    vrsub_vx(vd, vs2, Register::Zero, vm)
+}
+
+#[inline]
+pub fn vmmv_m(vd: VRegister, vs: VRegister) -> u32 {
+    vmand_mm(vd, vs, vs)
+}
+
+#[inline]
+pub fn vmclr_m(vd: VRegister) -> u32 {
+    vmxor_mm(vd, vd, vd)
+}
+
+#[inline]
+pub fn vmset_m(vd: VRegister) -> u32 {
+    vmxnor_mm(vd, vd, vd)
+}
+
+#[inline]
+pub fn vmnot_m(vd: VRegister, vs: VRegister) -> u32 {
+    vmnand_mm(vd, vs, vs)
+}
+
+#[inline]
+pub fn vfadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000000, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfadd_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000000, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000010, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000010, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmin_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000100, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfmin_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000100, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmax_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000110, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfmax_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000110, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfsgnj_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001000, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfsgnj_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001000, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfsgnjn_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001001, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfsgnjn_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001001, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfsgnjx_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001010, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfsgnjx_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001010, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfslide1up_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001110, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfslide1down_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001111, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmfeq_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011000, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmfeq_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011000, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmfle_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011001, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmfle_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011001, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmflt_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011011, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmflt_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011011, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmfne_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011100, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vmfne_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011100, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmfgt_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011101, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vmfge_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b011111, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfdiv_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100000, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfdiv_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100000, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfrdiv_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100001, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100100, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfmul_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100100, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfrsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b100111, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110000, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwadd_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110000, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110010, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110010, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111000, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwmul_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111000, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfredusum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000001, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfredosum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000011, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfredmin_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000101, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfredmax_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000111, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwredusum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110001, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwredosum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110011, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwadd_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110100, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwadd_wf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110100, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwsub_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110110, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwsub_wf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110110, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101000, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfmadd_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101000, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101001, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmadd_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101001, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101010, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfmsub_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101010, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101011, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmsub_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101011, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101100, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101100, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101101, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101101, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101110, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101110, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101111, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfnmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b101111, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111100, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111100, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwnmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111101, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwnmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111101, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111110, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111110, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfwnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111111, funct3: 0b001, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vfwnmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b111111, funct3: 0b101, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmerge_vfm(vd: VRegister, vs2: VRegister, rs1: FRegister) -> u32 {
+    VArithType { funct6: 0b010111, funct3: 0b101, vm: VMask::Masked, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmv_v_f(vd: VRegister, rs1: FRegister) -> u32 {
+    VArithType { funct6: 0b010111, funct3: 0b101, vm: VMask::Unmasked, vd, vs2: VRegister::V0, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfmv_f_s(rd: FRegister, vs2: VRegister) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b001, vm: VMask::Unmasked, vd: rd, vs2, rs1: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn vfmv_s_f(vd: VRegister, rs1: FRegister) -> u32 {
+    VArithType { funct6: 0b010000, funct3: 0b101, vm: VMask::Unmasked, vd, vs2: VRegister::V0, rs1 }.encode()
+}
+
+#[inline]
+pub fn vfcvt_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn vfcvt_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn vfcvt_f_xu_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn vfcvt_f_x_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn vfcvt_rtz_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b00110_u8 }.encode()
+}
+
+#[inline]
+pub fn vfcvt_rtz_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b00111_u8 }.encode()
+}
+
+#[inline]
+pub fn vfwcvt_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b01000_u8 }.encode()
+}
+
+#[inline]
+pub fn vfwcvt_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b01001_u8 }.encode()
+}
+
+#[inline]
+pub fn vfwcvt_f_xu_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b01010_u8 }.encode()
+}
+
+#[inline]
+pub fn vfwcvt_f_x_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b01011_u8 }.encode()
+}
+
+#[inline]
+pub fn vfwcvt_f_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b01100_u8 }.encode()
+}
+
+#[inline]
+pub fn vfwcvt_rtz_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b01110_u8 }.encode()
+}
+
+#[inline]
+pub fn vfwcvt_rtz_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b01111_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_xu_f_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10000_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_x_f_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10001_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_f_xu_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10010_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_f_x_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10011_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_f_f_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10100_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_rod_f_f_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10101_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_rtz_xu_f_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10110_u8 }.encode()
+}
+
+#[inline]
+pub fn vfncvt_rtz_x_f_w(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b001, vm, vd, vs2, rs1: 0b10111_u8 }.encode()
+}
+
+#[inline]
+pub fn vfsqrt_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b001, vm, vd, vs2, rs1: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn vfrsqrt7_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b001, vm, vd, vs2, rs1: 0b00100_u8 }.encode()
+}
+
+#[inline]
+pub fn vfrec7_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b001, vm, vd, vs2, rs1: 0b00101_u8 }.encode()
+}
+
+#[inline]
+pub fn vfclass_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010011, funct3: 0b001, vm, vd, vs2, rs1: 0b10000_u8 }.encode()
+}
+
+#[inline]
+pub fn vfneg_v(vd: VRegister, vs: VRegister, vm: VMask) -> u32 {
+    vfsgnjn_vv(vd, vs, vs, vm)
+}
+
+#[inline]
+pub fn vfabs_v(vd: VRegister, vs: VRegister, vm: VMask) -> u32 {
+    vfsgnjx_vv(vd, vs, vs, vm)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::VRegister::*;
+    #[test]
+    fn test_vaadd() {
+        assert_eq!(0x270c2457, vaadd_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x250c2457, vaadd_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x27056457, vaadd_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x25056457, vaadd_vx(V8, V16, A0, VMask::Masked));
+    }
+    #[test]
+    fn test_vaaddu() {
+        assert_eq!(0x230c2457, vaaddu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x210c2457, vaaddu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x23056457, vaaddu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x21056457, vaaddu_vx(V8, V16, A0, VMask::Masked));
+    }
+    use crate::types::FRegister::*;
+
+    #[test]
+    fn test_advance() {
+        assert_eq!(vec![
+            0x40560633, // sub a2, a2, t0
+            0x00550533, // add a0, a0, t0
+        ], advance(A2, T0, Sew::E8, &[A0]));
+        assert_eq!(vec![
+            0x40560633, // sub a2, a2, t0
+            0x00129293, // slli t0, t0, 1
+            0x00550533, // add a0, a0, t0
+            0x005585b3, // add a1, a1, t0
+        ], advance(A2, T0, Sew::E16, &[A0, A1]));
+        assert_eq!(vec![
+            0x40560633, // sub a2, a2, t0
+            0x00329293, // slli t0, t0, 3
+        ], advance(A2, T0, Sew::E64, &[]));
+    }
+
+    #[test]
+    fn test_strip_mine() {
+        assert_eq!(vec![
+            0x010672d7, // vsetvli t0, a2, e32, m1, tu, mu
+            0x022180d7, // vadd.vv v1, v2, v3
+            0x40560633, // sub a2, a2, t0
+            0x00229293, // slli t0, t0, 2
+            0x00550533, // add a0, a0, t0
+            0xfe0616e3, // bnez a2, -20
+        ], strip_mine(A2, T0, VType::new(Sew::E32, Lmul::M1), &[A0], &[vadd_vv(V1, V2, V3, VMask::Unmasked)]));
+    }
+
+    #[test]
+    fn test_vadc() {
+        assert_eq!(0x410c0457, vadc_vvm(V8, V16, V24));
+        assert_eq!(0x41054457, vadc_vxm(V8, V16, A0));
+        assert_eq!(0x410db457, vadc_vim(V8, V16, -5));
+        assert_eq!(0x4107b457, vadc_vim(V8, V16, 15));
+    }
+
+    #[test]
+    fn test_vadd() {
+        assert_eq!(0x030c0457, vadd_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x010c0457, vadd_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x03054457, vadd_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x01054457, vadd_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x030db457, vadd_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x010db457, vadd_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x0307b457, vadd_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vand() {
+        assert_eq!(0x270c0457, vand_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x250c0457, vand_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x27054457, vand_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x25054457, vand_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x270db457, vand_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x250db457, vand_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x2707b457, vand_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vasub() {
+        assert_eq!(0x2f0c2457, vasub_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x2d0c2457, vasub_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x2f056457, vasub_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x2d056457, vasub_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vasubu() {
+        assert_eq!(0x2b0c2457, vasubu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x290c2457, vasubu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x2b056457, vasubu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x29056457, vasubu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vcompress() {
+        assert_eq!(0x5f0c2457, vcompress_vm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vcpop() {
+        assert_eq!(0x430825d7, vcpop_m(A1, V16, VMask::Unmasked));
+        assert_eq!(0x410825d7, vcpop_m(A1, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vdiv() {
+        assert_eq!(0x870c2457, vdiv_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x850c2457, vdiv_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x87056457, vdiv_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x85056457, vdiv_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vdivu() {
+        assert_eq!(0x830c2457, vdivu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x810c2457, vdivu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x83056457, vdivu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x81056457, vdivu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfclass_v() {
+        assert_eq!(0x4f081457, vfclass_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x4d081457, vfclass_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfcvt_f_x_v() {
+        assert_eq!(0x4b019457, vfcvt_f_x_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49019457, vfcvt_f_x_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfcvt_f_xu_v() {
+        assert_eq!(0x4b011457, vfcvt_f_xu_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49011457, vfcvt_f_xu_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfcvt_rtz_x_f_v() {
+        assert_eq!(0x4b039457, vfcvt_rtz_x_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49039457, vfcvt_rtz_x_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfcvt_rtz_xu_f_v() {
+        assert_eq!(0x4b031457, vfcvt_rtz_xu_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49031457, vfcvt_rtz_xu_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfcvt_x_f_v() {
+        assert_eq!(0x4b009457, vfcvt_x_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49009457, vfcvt_x_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfcvt_xu_f_v() {
+        assert_eq!(0x4b001457, vfcvt_xu_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49001457, vfcvt_xu_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfdot_s() {
+        assert_eq!(vec![
+            0x0d2072d7, // vsetvli t0, zero, e32, m4, ta, ma
+            0x5e003457, // vmv.v.i v8, 0
+            0x012672d7, // vsetvli t0, a2, e32, m4, tu, mu
+            0x02056807, // vle32.v v16, (a0)
+            0x0205ec07, // vle32.v v24, (a1)
+            0xb3881457, // vfmacc.vv v8, v16, v24
+            0x40560633, // sub a2, a2, t0
+            0x00229293, // slli t0, t0, 2
+            0x00550533, // add a0, a0, t0
+            0x005585b3, // add a1, a1, t0
+            0xfe0610e3, // bnez a2, -32
+            0x0d2072d7, // vsetvli t0, zero, e32, m4, ta, ma
+            0x42055857, // vfmv.s.f v16, fa0
+            0x06881857, // vfredusum.vs v16, v8, v16
+            0x43001557, // vfmv.f.s fa0, v16
+        ], vfdot_s(Fa0, A0, A1, A2, T0, V8, V16, V24));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vfdot_s_misaligned() {
+        vfdot_s(Fa0, A0, A1, A2, T0, V8, V18, V24);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vfdot_s_overlap() {
+        vfdot_s(Fa0, A0, A1, A2, T0, V8, V8, V24);
+    }
+
+    #[test]
+    fn test_vfirst() {
+        assert_eq!(0x4308a5d7, vfirst_m(A1, V16, VMask::Unmasked));
+        assert_eq!(0x4108a5d7, vfirst_m(A1, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_f_f_w() {
+        assert_eq!(0x4b0a1457, vfncvt_f_f_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x490a1457, vfncvt_f_f_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_f_x_w() {
+        assert_eq!(0x4b099457, vfncvt_f_x_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49099457, vfncvt_f_x_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_f_xu_w() {
+        assert_eq!(0x4b091457, vfncvt_f_xu_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49091457, vfncvt_f_xu_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_rod_f_f_w() {
+        assert_eq!(0x4b0a9457, vfncvt_rod_f_f_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x490a9457, vfncvt_rod_f_f_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_rtz_x_f_w() {
+        assert_eq!(0x4b0b9457, vfncvt_rtz_x_f_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x490b9457, vfncvt_rtz_x_f_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_rtz_xu_f_w() {
+        assert_eq!(0x4b0b1457, vfncvt_rtz_xu_f_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x490b1457, vfncvt_rtz_xu_f_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_x_f_w() {
+        assert_eq!(0x4b089457, vfncvt_x_f_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49089457, vfncvt_x_f_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfncvt_xu_f_w() {
+        assert_eq!(0x4b081457, vfncvt_xu_f_w(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49081457, vfncvt_xu_f_w(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfrec7_v() {
+        assert_eq!(0x4f029457, vfrec7_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x4d029457, vfrec7_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfrsqrt7_v() {
+        assert_eq!(0x4f021457, vfrsqrt7_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x4d021457, vfrsqrt7_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfsqrt_v() {
+        assert_eq!(0x4f001457, vfsqrt_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x4d001457, vfsqrt_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfwcvt_f_f_v() {
+        assert_eq!(0x4b061457, vfwcvt_f_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49061457, vfwcvt_f_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfwcvt_f_x_v() {
+        assert_eq!(0x4b059457, vfwcvt_f_x_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49059457, vfwcvt_f_x_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfwcvt_f_xu_v() {
+        assert_eq!(0x4b051457, vfwcvt_f_xu_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49051457, vfwcvt_f_xu_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfwcvt_rtz_x_f_v() {
+        assert_eq!(0x4b079457, vfwcvt_rtz_x_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49079457, vfwcvt_rtz_x_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfwcvt_rtz_xu_f_v() {
+        assert_eq!(0x4b071457, vfwcvt_rtz_xu_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49071457, vfwcvt_rtz_xu_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfwcvt_x_f_v() {
+        assert_eq!(0x4b049457, vfwcvt_x_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49049457, vfwcvt_x_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vfwcvt_xu_f_v() {
+        assert_eq!(0x4b041457, vfwcvt_xu_f_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49041457, vfwcvt_xu_f_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vid() {
+        assert_eq!(0x5208a457, vid_v(V8, VMask::Unmasked));
+        assert_eq!(0x5008a457, vid_v(V8, VMask::Masked));
+    }
+
+    #[test]
+    fn test_viota() {
+        assert_eq!(0x53082457, viota_m(V8, V16, VMask::Unmasked));
+        assert_eq!(0x51082457, viota_m(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vle_v() {
+        assert_eq!(0x02050407, vle_v(Sew::E8, V8, A0, VMask::Unmasked, VType::new(Sew::E8, Lmul::M8)));
+        assert_eq!(0x00016207, vle_v(Sew::E32, V4, Sp, VMask::Masked, VType::new(Sew::E32, Lmul::M4)));
+        assert_eq!(0x0205f107, vle_v(Sew::E64, V2, A1, VMask::Unmasked, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vle_v_group_overflow() {
+        vle_v(Sew::E8, V28, A0, VMask::Unmasked, VType::new(Sew::E8, Lmul::M8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vle_v_masked_v0() {
+        vle_v(Sew::E8, V0, A0, VMask::Masked, VType::new(Sew::E8, Lmul::M1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vle_v_misaligned() {
+        vle_v(Sew::E32, V2, A0, VMask::Unmasked, VType::new(Sew::E32, Lmul::M4));
+    }
+
+    #[test]
+    fn test_vleff_v() {
+        assert_eq!(0x01055087, vleff_v(Sew::E16, V1, A0, VMask::Masked, VType::new(Sew::E16, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vlm_v() {
+        assert_eq!(0x02b50087, vlm_v(V1, A0));
+    }
+
+    #[test]
+    fn test_vloxei_v() {
+        assert_eq!(0x0f055407, vloxei_v(Sew::E16, V8, A0, V16, VMask::Unmasked, VType::new(Sew::E16, Lmul::M2)));
+    }
+
+    #[test]
+    fn test_vloxseg_v() {
+        assert_eq!(0x4f050407, vloxseg_v(3, Sew::E8, V8, A0, V16, VMask::Unmasked, VType::new(Sew::E8, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vlre_v() {
+        assert_eq!(0x02850187, vlre_v(1, Sew::E8, V3, A0));
+        assert_eq!(0x62856207, vlre_v(4, Sew::E32, V4, A0));
+        assert_eq!(0xe2857407, vlre_v(8, Sew::E64, V8, A0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vlre_v_not_power_of_two() {
+        vlre_v(3, Sew::E8, V0, A0);
+    }
+
+    #[test]
+    fn test_vlse_v() {
+        assert_eq!(0x0ab56407, vlse_v(Sew::E32, V8, A0, A1, VMask::Unmasked, VType::new(Sew::E32, Lmul::M2)));
+        assert_eq!(0x08655187, vlse_v(Sew::E16, V3, A0, T1, VMask::Masked, VType::new(Sew::E16, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vlseg_v() {
+        assert_eq!(0x42056207, vlseg_v(3, Sew::E32, V4, A0, VMask::Unmasked, VType::new(Sew::E32, Lmul::M2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vlseg_v_group_too_large() {
+        vlseg_v(3, Sew::E8, V0, A0, VMask::Unmasked, VType::new(Sew::E8, Lmul::M4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vlseg_v_too_many_fields() {
+        vlseg_v(9, Sew::E8, V0, A0, VMask::Unmasked, VType::new(Sew::E8, Lmul::M1));
+    }
+
+    #[test]
+    fn test_vlsegff_v() {
+        assert_eq!(0x21050107, vlsegff_v(2, Sew::E8, V2, A0, VMask::Masked, VType::new(Sew::E8, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vlsseg_v() {
+        assert_eq!(0x6ab57407, vlsseg_v(4, Sew::E64, V8, A0, A1, VMask::Unmasked, VType::new(Sew::E64, Lmul::M2)));
+    }
+
+    #[test]
+    fn test_vluxei_v() {
+        assert_eq!(0x07056407, vluxei_v(Sew::E32, V8, A0, V16, VMask::Unmasked, VType::new(Sew::E8, Lmul::M1)));
+        assert_eq!(0x04250207, vluxei_v(Sew::E8, V4, A0, V2, VMask::Masked, VType::new(Sew::E32, Lmul::M4)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vluxei_v_misaligned_index() {
+        vluxei_v(Sew::E32, V8, A0, V17, VMask::Unmasked, VType::new(Sew::E8, Lmul::M1));
+    }
+
+    #[test]
+    fn test_vluxseg_v() {
+        assert_eq!(0x27055407, vluxseg_v(2, Sew::E16, V8, A0, V16, VMask::Unmasked, VType::new(Sew::E32, Lmul::M2)));
+    }
+
+    #[test]
+    fn test_vmacc() {
+        assert_eq!(0xb70c2457, vmacc_vv(V8, V24, V16, VMask::Unmasked));
+        assert_eq!(0xb50c2457, vmacc_vv(V8, V24, V16, VMask::Masked));
+        assert_eq!(0xb7056457, vmacc_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xb5056457, vmacc_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmadc() {
+        assert_eq!(0x450c0457, vmadc_vvm(V8, V16, V24));
+        assert_eq!(0x45054457, vmadc_vxm(V8, V16, A0));
+        assert_eq!(0x450db457, vmadc_vim(V8, V16, -5));
+        assert_eq!(0x4507b457, vmadc_vim(V8, V16, 15));
+        assert_eq!(0x470c0457, vmadc_vv(V8, V16, V24));
+        assert_eq!(0x47054457, vmadc_vx(V8, V16, A0));
+        assert_eq!(0x470db457, vmadc_vi(V8, V16, -5));
+        assert_eq!(0x4707b457, vmadc_vi(V8, V16, 15));
+    }
+
+    #[test]
+    fn test_vmadd() {
+        assert_eq!(0xa70c2457, vmadd_vv(V8, V24, V16, VMask::Unmasked));
+        assert_eq!(0xa50c2457, vmadd_vv(V8, V24, V16, VMask::Masked));
+        assert_eq!(0xa7056457, vmadd_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xa5056457, vmadd_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmand() {
+        assert_eq!(0x670c2457, vmand_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vmandn() {
+        assert_eq!(0x630c2457, vmandn_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vmax() {
+        assert_eq!(0x1f0c0457, vmax_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x1d0c0457, vmax_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x1f054457, vmax_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x1d054457, vmax_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmaxu() {
+        assert_eq!(0x1b0c0457, vmaxu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x190c0457, vmaxu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x1b054457, vmaxu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x19054457, vmaxu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmclr() {
+        assert_eq!(0x6e842457, vmclr_m(V8));
+    }
+
+    #[test]
+    fn test_vmemcpy() {
+        assert_eq!(vec![
+            0x0c3672d7, // vsetvli t0, a2, e8, m8, ta, ma
+            0x02058407, // vle8.v v8, (a1)
+            0x02050427, // vse8.v v8, (a0)
+            0x40560633, // sub a2, a2, t0
+            0x00550533, // add a0, a0, t0
+            0x005585b3, // add a1, a1, t0
+            0xfe0614e3, // bnez a2, -24
+        ], vmemcpy(A0, A1, A2, T0, V8));
+    }
+
+    #[test]
+    fn test_vmemset() {
+        assert_eq!(vec![
+            0x0c3672d7, // vsetvli t0, a2, e8, m8, ta, ma
+            0x5e05c457, // vmv.v.x v8, a1
+            0x02050427, // vse8.v v8, (a0)
+            0x40560633, // sub a2, a2, t0
+            0x00550533, // add a0, a0, t0
+            0xfe0616e3, // bnez a2, -20
+        ], vmemset(A0, A1, A2, T0, V8));
+    }
+
+    #[test]
+    fn test_vmerge() {
+        assert_eq!(0x5d0c0457, vmerge_vvm(V8, V16, V24));
+        assert_eq!(0x5d054457, vmerge_vxm(V8, V16, A0));
+        assert_eq!(0x5d0db457, vmerge_vim(V8, V16, -5));
+        assert_eq!(0x5d07b457, vmerge_vim(V8, V16, 15));
+    }
+
+    #[test]
+    fn test_vmin() {
+        assert_eq!(0x170c0457, vmin_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x150c0457, vmin_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x17054457, vmin_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x15054457, vmin_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vminu() {
+        assert_eq!(0x130c0457, vminu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x110c0457, vminu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x13054457, vminu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x11054457, vminu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmmv() {
+        assert_eq!(0x67082457, vmmv_m(V8, V16));
+    }
+
+    #[test]
+    fn test_vmnand() {
+        assert_eq!(0x770c2457, vmnand_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vmnor() {
+        assert_eq!(0x7b0c2457, vmnor_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vmnot() {
+        assert_eq!(0x77082457, vmnot_m(V8, V16));
+    }
+
+    #[test]
+    fn test_vmor() {
+        assert_eq!(0x6b0c2457, vmor_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vmorn() {
+        assert_eq!(0x730c2457, vmorn_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vmsbc() {
+        assert_eq!(0x4d0c0457, vmsbc_vvm(V8, V16, V24));
+        assert_eq!(0x4d054457, vmsbc_vxm(V8, V16, A0));
+        assert_eq!(0x4f0c0457, vmsbc_vv(V8, V16, V24));
+        assert_eq!(0x4f054457, vmsbc_vx(V8, V16, A0));
+    }
+
+    #[test]
+    fn test_vmsbf() {
+        assert_eq!(0x5300a457, vmsbf_m(V8, V16, VMask::Unmasked));
+        assert_eq!(0x5100a457, vmsbf_m(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmseq() {
+        assert_eq!(0x630c0457, vmseq_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x610c0457, vmseq_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x63054457, vmseq_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x61054457, vmseq_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x630db457, vmseq_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x610db457, vmseq_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x6307b457, vmseq_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vmset() {
+        assert_eq!(0x7e842457, vmset_m(V8));
+    }
+
+    #[test]
+    fn test_vmsgt() {
+        assert_eq!(0x7f054457, vmsgt_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x7d054457, vmsgt_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x7f0db457, vmsgt_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x7d0db457, vmsgt_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x7f07b457, vmsgt_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vmsgtu() {
+        assert_eq!(0x7b054457, vmsgtu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x79054457, vmsgtu_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x7b0db457, vmsgtu_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x790db457, vmsgtu_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x7b07b457, vmsgtu_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vmsif() {
+        assert_eq!(0x5301a457, vmsif_m(V8, V16, VMask::Unmasked));
+        assert_eq!(0x5101a457, vmsif_m(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmsle() {
+        assert_eq!(0x770c0457, vmsle_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x750c0457, vmsle_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x77054457, vmsle_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x75054457, vmsle_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x770db457, vmsle_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x750db457, vmsle_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x7707b457, vmsle_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vmsleu() {
+        assert_eq!(0x730c0457, vmsleu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x710c0457, vmsleu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x73054457, vmsleu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x71054457, vmsleu_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x730db457, vmsleu_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x710db457, vmsleu_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x7307b457, vmsleu_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vmslt() {
+        assert_eq!(0x6f0c0457, vmslt_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x6d0c0457, vmslt_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x6f054457, vmslt_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x6d054457, vmslt_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmsltu() {
+        assert_eq!(0x6b0c0457, vmsltu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x690c0457, vmsltu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x6b054457, vmsltu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x69054457, vmsltu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmsne() {
+        assert_eq!(0x670c0457, vmsne_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x650c0457, vmsne_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x67054457, vmsne_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x65054457, vmsne_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x670db457, vmsne_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x650db457, vmsne_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x6707b457, vmsne_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vmsof() {
+        assert_eq!(0x53012457, vmsof_m(V8, V16, VMask::Unmasked));
+        assert_eq!(0x51012457, vmsof_m(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmul() {
+        assert_eq!(0x970c2457, vmul_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x950c2457, vmul_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x97056457, vmul_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x95056457, vmul_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmulh() {
+        assert_eq!(0x9f0c2457, vmulh_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x9d0c2457, vmulh_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x9f056457, vmulh_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x9d056457, vmulh_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmulhsu() {
+        assert_eq!(0x9b0c2457, vmulhsu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x990c2457, vmulhsu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x9b056457, vmulhsu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x99056457, vmulhsu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmulhu() {
+        assert_eq!(0x930c2457, vmulhu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x910c2457, vmulhu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x93056457, vmulhu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x91056457, vmulhu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vmv_s() {
+        assert_eq!(0x42056457, vmv_s_x(V8, A0));
+    }
+
+    #[test]
+    fn test_vmv_v() {
+        assert_eq!(0x5e0c0457, vmv_v_v(V8, V24));
+        assert_eq!(0x5e054457, vmv_v_x(V8, A0));
+        assert_eq!(0x5e0db457, vmv_v_i(V8, -5));
+        assert_eq!(0x5e07b457, vmv_v_i(V8, 15));
+    }
+
+    #[test]
+    fn test_vmv_x() {
+        assert_eq!(0x430025d7, vmv_x_s(A1, V16));
+    }
+
+    #[test]
+    fn test_vmvr() {
+        assert_eq!(0x9f003457, vmvr_v(1, V8, V16));
+        assert_eq!(0x9f00b457, vmvr_v(2, V8, V16));
+        assert_eq!(0x9f01b457, vmvr_v(4, V8, V16));
+        assert_eq!(0x9f03b457, vmvr_v(8, V8, V16));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vmvr_v_misaligned() {
+        vmvr_v(4, V8, V18);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vmvr_v_not_power_of_two() {
+        vmvr_v(3, V8, V16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vmvr_v_too_many() {
+        vmvr_v(16, V0, V16);
+    }
+
+    #[test]
+    fn test_vmxnor() {
+        assert_eq!(0x7f0c2457, vmxnor_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vmxor() {
+        assert_eq!(0x6f0c2457, vmxor_mm(V8, V16, V24));
+    }
+
+    #[test]
+    fn test_vnclip() {
+        assert_eq!(0xbf0c0457, vnclip_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xbd0c0457, vnclip_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xbf054457, vnclip_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xbd054457, vnclip_wx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xbf03b457, vnclip_wi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xbd03b457, vnclip_wi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xbf0fb457, vnclip_wi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vnclipu() {
+        assert_eq!(0xbb0c0457, vnclipu_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xb90c0457, vnclipu_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xbb054457, vnclipu_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xb9054457, vnclipu_wx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xbb03b457, vnclipu_wi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xb903b457, vnclipu_wi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xbb0fb457, vnclipu_wi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vneg() {
+        assert_eq!(0x0f004457, vneg_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x0d004457, vneg_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vnmsac() {
+        assert_eq!(0xbf0c2457, vnmsac_vv(V8, V24, V16, VMask::Unmasked));
+        assert_eq!(0xbd0c2457, vnmsac_vv(V8, V24, V16, VMask::Masked));
+        assert_eq!(0xbf056457, vnmsac_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xbd056457, vnmsac_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vnmsub() {
+        assert_eq!(0xaf0c2457, vnmsub_vv(V8, V24, V16, VMask::Unmasked));
+        assert_eq!(0xad0c2457, vnmsub_vv(V8, V24, V16, VMask::Masked));
+        assert_eq!(0xaf056457, vnmsub_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xad056457, vnmsub_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vnot() {
+        assert_eq!(0x2f0fb457, vnot_v(V8, V16, VMask::Unmasked));
+        assert_eq!(0x2d0fb457, vnot_v(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vnsra() {
+        assert_eq!(0xb70c0457, vnsra_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xb50c0457, vnsra_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xb7054457, vnsra_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xb5054457, vnsra_wx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xb703b457, vnsra_wi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xb503b457, vnsra_wi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xb70fb457, vnsra_wi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vnsrl() {
+        assert_eq!(0xb30c0457, vnsrl_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xb10c0457, vnsrl_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xb3054457, vnsrl_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xb1054457, vnsrl_wx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xb303b457, vnsrl_wi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xb103b457, vnsrl_wi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xb30fb457, vnsrl_wi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vor() {
+        assert_eq!(0x2b0c0457, vor_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x290c0457, vor_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x2b054457, vor_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x29054457, vor_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x2b0db457, vor_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x290db457, vor_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x2b07b457, vor_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vredand() {
+        assert_eq!(0x070c2457, vredand_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x050c2457, vredand_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vredmax() {
+        assert_eq!(0x1f0c2457, vredmax_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x1d0c2457, vredmax_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vredmaxu() {
+        assert_eq!(0x1b0c2457, vredmaxu_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x190c2457, vredmaxu_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vredmin() {
+        assert_eq!(0x170c2457, vredmin_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x150c2457, vredmin_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vredminu() {
+        assert_eq!(0x130c2457, vredminu_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x110c2457, vredminu_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vredor() {
+        assert_eq!(0x0b0c2457, vredor_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x090c2457, vredor_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vredsum() {
+        assert_eq!(0x030c2457, vredsum_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x010c2457, vredsum_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vredxor() {
+        assert_eq!(0x0f0c2457, vredxor_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x0d0c2457, vredxor_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vrem() {
+        assert_eq!(0x8f0c2457, vrem_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x8d0c2457, vrem_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x8f056457, vrem_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x8d056457, vrem_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vremu() {
+        assert_eq!(0x8b0c2457, vremu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x890c2457, vremu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x8b056457, vremu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x89056457, vremu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vrgather() {
+        assert_eq!(0x330c0457, vrgather_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x310c0457, vrgather_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x33054457, vrgather_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x31054457, vrgather_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x3303b457, vrgather_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0x3103b457, vrgather_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0x330fb457, vrgather_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vrgatherei16() {
+        assert_eq!(0x3b0c0457, vrgatherei16_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x390c0457, vrgatherei16_vv(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vrsub() {
+        assert_eq!(0x0f054457, vrsub_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x0d054457, vrsub_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x0f0db457, vrsub_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x0d0db457, vrsub_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x0f07b457, vrsub_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vsadd() {
+        assert_eq!(0x870c0457, vsadd_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x850c0457, vsadd_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x87054457, vsadd_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x85054457, vsadd_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x870db457, vsadd_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x850db457, vsadd_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x8707b457, vsadd_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vsaddu() {
+        assert_eq!(0x830c0457, vsaddu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x810c0457, vsaddu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x83054457, vsaddu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x81054457, vsaddu_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x830db457, vsaddu_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x810db457, vsaddu_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x8307b457, vsaddu_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vsbc() {
+        assert_eq!(0x490c0457, vsbc_vvm(V8, V16, V24));
+        assert_eq!(0x49054457, vsbc_vxm(V8, V16, A0));
+    }
+
+    #[test]
+    fn test_vse_v() {
+        assert_eq!(0x02050427, vse_v(Sew::E8, V8, A0, VMask::Unmasked, VType::new(Sew::E8, Lmul::M8)));
+        assert_eq!(0x0002f027, vse_v(Sew::E64, V0, T0, VMask::Masked, VType::new(Sew::E64, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vsetivli() {
+        assert_eq!(0xc89ff557, vsetivli(A0, 31, VType::new(Sew::E16, Lmul::M2).mask(MaskPolicy::Agnostic)));
+        assert_eq!(0xc0507057, vsetivli(Zero, 0, VType::new(Sew::E8, Lmul::Mf8)));
+    }
+
+    #[test]
+    fn test_vsetvl() {
+        assert_eq!(0x80c5f557, vsetvl(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_vsetvli() {
+        assert_eq!(0x0005f557, vsetvli(A0, A1, VType::new(Sew::E8, Lmul::M1)));
+        assert_eq!(0x0d3072d7, vsetvli(T0, Zero, VType::new(Sew::E32, Lmul::M8).tail(TailPolicy::Agnostic).mask(MaskPolicy::Agnostic)));
+        assert_eq!(0x05f57057, vsetvli(Zero, A0, VType::new(Sew::E64, Lmul::Mf2).tail(TailPolicy::Agnostic)));
+    }
+
+    #[test]
+    fn test_vsext() {
+        assert_eq!(0x4b01a457, vsext_vf8(V8, V16, VMask::Unmasked));
+        assert_eq!(0x4901a457, vsext_vf8(V8, V16, VMask::Masked));
+        assert_eq!(0x4b02a457, vsext_vf4(V8, V16, VMask::Unmasked));
+        assert_eq!(0x4902a457, vsext_vf4(V8, V16, VMask::Masked));
+        assert_eq!(0x4b03a457, vsext_vf2(V8, V16, VMask::Unmasked));
+        assert_eq!(0x4903a457, vsext_vf2(V8, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vslide1down() {
+        assert_eq!(0x3f056457, vslide1down_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x3d056457, vslide1down_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vslide1up() {
+        assert_eq!(0x3b056457, vslide1up_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x39056457, vslide1up_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vslidedown() {
+        assert_eq!(0x3f054457, vslidedown_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x3d054457, vslidedown_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x3f03b457, vslidedown_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0x3d03b457, vslidedown_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0x3f0fb457, vslidedown_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vslideup() {
+        assert_eq!(0x3b054457, vslideup_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x39054457, vslideup_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x3b03b457, vslideup_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0x3903b457, vslideup_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0x3b0fb457, vslideup_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vsll() {
+        assert_eq!(0x970c0457, vsll_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x950c0457, vsll_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x97054457, vsll_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x95054457, vsll_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x9703b457, vsll_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0x9503b457, vsll_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0x970fb457, vsll_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vsm_v() {
+        assert_eq!(0x02b500a7, vsm_v(V1, A0));
+    }
+
+    #[test]
+    fn test_vsmul() {
+        assert_eq!(0x9f0c0457, vsmul_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x9d0c0457, vsmul_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x9f054457, vsmul_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x9d054457, vsmul_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vsoxei_v() {
+        assert_eq!(0x0d050427, vsoxei_v(Sew::E8, V8, A0, V16, VMask::Masked, VType::new(Sew::E8, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vsoxseg_v() {
+        assert_eq!(0x6f057427, vsoxseg_v(4, Sew::E64, V8, A0, V16, VMask::Unmasked, VType::new(Sew::E64, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vsr_v() {
+        assert_eq!(0x22850127, vsr_v(2, V2, A0));
+        assert_eq!(0xe2850c27, vsr_v(8, V24, A0));
+    }
+
+    #[test]
+    fn test_vsra() {
+        assert_eq!(0xa70c0457, vsra_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xa50c0457, vsra_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xa7054457, vsra_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xa5054457, vsra_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xa703b457, vsra_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xa503b457, vsra_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xa70fb457, vsra_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vsrl() {
+        assert_eq!(0xa30c0457, vsrl_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xa10c0457, vsrl_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xa3054457, vsrl_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xa1054457, vsrl_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xa303b457, vsrl_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xa103b457, vsrl_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xa30fb457, vsrl_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vsse_v() {
+        assert_eq!(0x0ab57427, vsse_v(Sew::E64, V8, A0, A1, VMask::Unmasked, VType::new(Sew::E64, Lmul::M8)));
+    }
+
+    #[test]
+    fn test_vsseg_v() {
+        assert_eq!(0xe2055427, vsseg_v(8, Sew::E16, V8, A0, VMask::Unmasked, VType::new(Sew::E16, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vssra() {
+        assert_eq!(0xaf0c0457, vssra_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xad0c0457, vssra_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xaf054457, vssra_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xad054457, vssra_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xaf03b457, vssra_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xad03b457, vssra_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xaf0fb457, vssra_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vssrl() {
+        assert_eq!(0xab0c0457, vssrl_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xa90c0457, vssrl_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xab054457, vssrl_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xa9054457, vssrl_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xab03b457, vssrl_vi(V8, V16, 7, VMask::Unmasked));
+        assert_eq!(0xa903b457, vssrl_vi(V8, V16, 7, VMask::Masked));
+        assert_eq!(0xab0fb457, vssrl_vi(V8, V16, 31, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vssseg_v() {
+        assert_eq!(0x28b56427, vssseg_v(2, Sew::E32, V8, A0, A1, VMask::Masked, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vssub() {
+        assert_eq!(0x8f0c0457, vssub_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x8d0c0457, vssub_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x8f054457, vssub_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x8d054457, vssub_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vssubu() {
+        assert_eq!(0x8b0c0457, vssubu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x890c0457, vssubu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x8b054457, vssubu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x89054457, vssubu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vstrlen() {
+        assert_eq!(vec![
+            0x0c3072d7, // vsetvli t0, zero, e8, m8, ta, ma
+            0x03050407, // vle8ff.v v8, (a0)
+            0xc20022f3, // csrr t0, vl
+            0x62803057, // vmseq.vi v0, v8, 0
+            0x4208a357, // vfirst.m t1, v0
+            0x00550533, // add a0, a0, t0
+            0x005585b3, // add a1, a1, t0
+            0xfe0342e3, // bltz t1, -28
+            0x405585b3, // sub a1, a1, t0
+            0x006585b3, // add a1, a1, t1
+        ], vstrlen(A0, A1, T0, T1, V8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vstrlen_v0() {
+        vstrlen(A0, A1, T0, T1, V0);
+    }
+
+    #[test]
+    fn test_vsub() {
+        assert_eq!(0x0b0c0457, vsub_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x090c0457, vsub_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x0b054457, vsub_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x09054457, vsub_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vsuxei_v() {
+        assert_eq!(0x07057427, vsuxei_v(Sew::E64, V8, A0, V16, VMask::Unmasked, VType::new(Sew::E64, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vsuxseg_v() {
+        assert_eq!(0x25056427, vsuxseg_v(2, Sew::E32, V8, A0, V16, VMask::Masked, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vwadd() {
+        assert_eq!(0xc70c2457, vwadd_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xc50c2457, vwadd_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xc7056457, vwadd_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xc5056457, vwadd_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xd70c2457, vwadd_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xd50c2457, vwadd_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xd7056457, vwadd_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xd5056457, vwadd_wx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwaddu() {
+        assert_eq!(0xc30c2457, vwaddu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xc10c2457, vwaddu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xc3056457, vwaddu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xc1056457, vwaddu_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xd30c2457, vwaddu_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xd10c2457, vwaddu_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xd3056457, vwaddu_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xd1056457, vwaddu_wx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwmacc() {
+        assert_eq!(0xf70c2457, vwmacc_vv(V8, V24, V16, VMask::Unmasked));
+        assert_eq!(0xf50c2457, vwmacc_vv(V8, V24, V16, VMask::Masked));
+        assert_eq!(0xf7056457, vwmacc_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xf5056457, vwmacc_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwmaccsu() {
+        assert_eq!(0xff0c2457, vwmaccsu_vv(V8, V24, V16, VMask::Unmasked));
+        assert_eq!(0xfd0c2457, vwmaccsu_vv(V8, V24, V16, VMask::Masked));
+        assert_eq!(0xff056457, vwmaccsu_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xfd056457, vwmaccsu_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwmaccu() {
+        assert_eq!(0xf30c2457, vwmaccu_vv(V8, V24, V16, VMask::Unmasked));
+        assert_eq!(0xf10c2457, vwmaccu_vv(V8, V24, V16, VMask::Masked));
+        assert_eq!(0xf3056457, vwmaccu_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xf1056457, vwmaccu_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwmaccus() {
+        assert_eq!(0xfb056457, vwmaccus_vx(V8, A0, V16, VMask::Unmasked));
+        assert_eq!(0xf9056457, vwmaccus_vx(V8, A0, V16, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwmul() {
+        assert_eq!(0xef0c2457, vwmul_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xed0c2457, vwmul_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xef056457, vwmul_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xed056457, vwmul_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwmulsu() {
+        assert_eq!(0xeb0c2457, vwmulsu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xe90c2457, vwmulsu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xeb056457, vwmulsu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xe9056457, vwmulsu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwmulu() {
+        assert_eq!(0xe30c2457, vwmulu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xe10c2457, vwmulu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xe3056457, vwmulu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xe1056457, vwmulu_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwredsum() {
+        assert_eq!(0xc70c0457, vwredsum_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xc50c0457, vwredsum_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwredsumu() {
+        assert_eq!(0xc30c0457, vwredsumu_vs(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xc10c0457, vwredsumu_vs(V8, V16, V24, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwsub() {
+        assert_eq!(0xcf0c2457, vwsub_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xcd0c2457, vwsub_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xcf056457, vwsub_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xcd056457, vwsub_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xdf0c2457, vwsub_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xdd0c2457, vwsub_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xdf056457, vwsub_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xdd056457, vwsub_wx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vwsubu() {
+        assert_eq!(0xcb0c2457, vwsubu_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xc90c2457, vwsubu_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xcb056457, vwsubu_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xc9056457, vwsubu_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0xdb0c2457, vwsubu_wv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0xd90c2457, vwsubu_wv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0xdb056457, vwsubu_wx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0xd9056457, vwsubu_wx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vxor() {
+        assert_eq!(0x2f0c0457, vxor_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x2d0c0457, vxor_vv(V8, V16, V24, VMask::Masked));
+        assert_eq!(0x2f054457, vxor_vx(V8, V16, A0, VMask::Unmasked));
+        assert_eq!(0x2d054457, vxor_vx(V8, V16, A0, VMask::Masked));
+        assert_eq!(0x2f0db457, vxor_vi(V8, V16, -5, VMask::Unmasked));
+        assert_eq!(0x2d0db457, vxor_vi(V8, V16, -5, VMask::Masked));
+        assert_eq!(0x2f07b457, vxor_vi(V8, V16, 15, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vzext() {
+        assert_eq!(0x4b012457, vzext_vf8(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49012457, vzext_vf8(V8, V16, VMask::Masked));
+        assert_eq!(0x4b022457, vzext_vf4(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49022457, vzext_vf4(V8, V16, VMask::Masked));
+        assert_eq!(0x4b032457, vzext_vf2(V8, V16, VMask::Unmasked));
+        assert_eq!(0x49032457, vzext_vf2(V8, V16, VMask::Masked));
+    }
+}
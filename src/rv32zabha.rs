@@ -0,0 +1,243 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_amoadd_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoadd_b;
+        emit_amoadd_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoadd_h;
+        emit_amoand_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoand_b;
+        emit_amoand_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoand_h;
+        emit_amocas_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amocas_b;
+        emit_amocas_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amocas_h;
+        emit_amomax_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomax_b;
+        emit_amomax_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomax_h;
+        emit_amomaxu_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomaxu_b;
+        emit_amomaxu_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomaxu_h;
+        emit_amomin_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomin_b;
+        emit_amomin_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomin_h;
+        emit_amominu_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amominu_b;
+        emit_amominu_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amominu_h;
+        emit_amoor_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoor_b;
+        emit_amoor_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoor_h;
+        emit_amoswap_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoswap_b;
+        emit_amoswap_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoswap_h;
+        emit_amoxor_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoxor_b;
+        emit_amoxor_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoxor_h;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn amoadd_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b00000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoswap_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b00001, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amocas_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b00101, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoxor_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b00100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoor_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b01000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoand_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b01100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomin_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b10000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomax_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b10100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amominu_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b11000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomaxu_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b000, funct5: 0b11100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoadd_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b00000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoswap_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b00001, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amocas_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b00101, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoxor_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b00100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoor_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b01000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoand_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b01100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomin_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b10000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomax_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b10100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amominu_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b11000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomaxu_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b001, funct5: 0b11100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::AmoOrdering::*;
+
+    #[test]
+    fn test_amoadd_b() {
+        assert_eq!(0x00b6052f, amoadd_b(A0, A1, A2, Relaxed));
+    }
+
+    #[test]
+    fn test_amoadd_h() {
+        assert_eq!(0x00b6152f, amoadd_h(A0, A1, A2, Relaxed));
+    }
+
+    #[test]
+    fn test_amoand_b() {
+        assert_eq!(0x62b6052f, amoand_b(A0, A1, A2, Release));
+    }
+
+    #[test]
+    fn test_amoand_h() {
+        assert_eq!(0x62b6152f, amoand_h(A0, A1, A2, Release));
+    }
+
+    #[test]
+    fn test_amocas_b() {
+        assert_eq!(0x2cb6052f, amocas_b(A0, A1, A2, Acquire));
+    }
+
+    #[test]
+    fn test_amocas_h() {
+        assert_eq!(0x2cb6152f, amocas_h(A0, A1, A2, Acquire));
+    }
+
+    #[test]
+    fn test_amomax_b() {
+        assert_eq!(0xa6b6052f, amomax_b(A0, A1, A2, AcqRel));
+    }
+
+    #[test]
+    fn test_amomax_h() {
+        assert_eq!(0xa6b6152f, amomax_h(A0, A1, A2, AcqRel));
+    }
+
+    #[test]
+    fn test_amomaxu_b() {
+        assert_eq!(0xe2b6052f, amomaxu_b(A0, A1, A2, Release));
+    }
+
+    #[test]
+    fn test_amomaxu_h() {
+        assert_eq!(0xe2b6152f, amomaxu_h(A0, A1, A2, Release));
+    }
+
+    #[test]
+    fn test_amomin_b() {
+        assert_eq!(0x84b6052f, amomin_b(A0, A1, A2, Acquire));
+    }
+
+    #[test]
+    fn test_amomin_h() {
+        assert_eq!(0x84b6152f, amomin_h(A0, A1, A2, Acquire));
+    }
+
+    #[test]
+    fn test_amominu_b() {
+        assert_eq!(0xc0b6052f, amominu_b(A0, A1, A2, Relaxed));
+    }
+
+    #[test]
+    fn test_amominu_h() {
+        assert_eq!(0xc0b6152f, amominu_h(A0, A1, A2, Relaxed));
+    }
+
+    #[test]
+    fn test_amoor_b() {
+        assert_eq!(0x40b6052f, amoor_b(A0, A1, A2, Relaxed));
+    }
+
+    #[test]
+    fn test_amoor_h() {
+        assert_eq!(0x40b6152f, amoor_h(A0, A1, A2, Relaxed));
+    }
+
+    #[test]
+    fn test_amoswap_b() {
+        assert_eq!(0x0ab6052f, amoswap_b(A0, A1, A2, Release));
+    }
+
+    #[test]
+    fn test_amoswap_h() {
+        assert_eq!(0x0ab6152f, amoswap_h(A0, A1, A2, Release));
+    }
+
+    #[test]
+    fn test_amoxor_b() {
+        assert_eq!(0x26b6052f, amoxor_b(A0, A1, A2, AcqRel));
+    }
+
+    #[test]
+    fn test_amoxor_h() {
+        assert_eq!(0x26b6152f, amoxor_h(A0, A1, A2, AcqRel));
+    }
+}
@@ -0,0 +1,61 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_amocas_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amocas_d;
+        emit_amocas_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amocas_w;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn amocas_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b010, funct5: 0b00101, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amocas_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    assert!(is_register_pair(rd));
+    assert!(is_register_pair(rs));
+    AType { funct3: 0b011, funct5: 0b00101, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::AmoOrdering::*;
+
+    #[test]
+    fn test_amocas_d() {
+        assert_eq!(0x2cc7b52f, amocas_d(A0, A2, A5, Acquire));
+        assert_eq!(0x2be1302f, amocas_d(Zero, T5, Sp, Release));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_amocas_d_odd_rd() {
+        amocas_d(A1, A2, A5, Relaxed);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_amocas_d_odd_rs() {
+        amocas_d(A0, A3, A5, Relaxed);
+    }
+
+    #[test]
+    fn test_amocas_w() {
+        assert_eq!(0x28b6252f, amocas_w(A0, A1, A2, Relaxed));
+        assert_eq!(0x2e6122af, amocas_w(T0, T1, Sp, AcqRel));
+    }
+}
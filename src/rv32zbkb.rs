@@ -0,0 +1,107 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zbb;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_brev8(rd: Register, rs: Register) => brev8;
+        emit_pack(rd: Register, rs1: Register, rs2: Register) => pack;
+        emit_packh(rd: Register, rs1: Register, rs2: Register) => packh;
+        emit_unzip(rd: Register, rs: Register) => unzip;
+        emit_zip(rd: Register, rs: Register) => zip;
+    }
+
+    forward! {
+        emit_andn(rd: Register, rs1: Register, rs2: Register) => rv32zbb::andn;
+        emit_orn(rd: Register, rs1: Register, rs2: Register) => rv32zbb::orn;
+        emit_rev8(rd: Register, rs: Register) => rv32zbb::rev8;
+        emit_rol(rd: Register, rs1: Register, rs2: Register) => rv32zbb::rol;
+        emit_ror(rd: Register, rs1: Register, rs2: Register) => rv32zbb::ror;
+        emit_rori(rd: Register, rs: Register, shamt: u8) => rv32zbb::rori;
+        emit_xnor(rd: Register, rs1: Register, rs2: Register) => rv32zbb::xnor;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn brev8(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b101, imm12: 0b0110100_00111, rd, rs }.encode()
+}
+
+#[inline]
+pub fn pack(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b100, funct7: 0b0000100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn packh(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b111, funct7: 0b0000100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn unzip(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b101, imm12: 0b0000100_01111, rd, rs }.encode()
+}
+
+#[inline]
+pub fn zip(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0000100_01111, rd, rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    struct Buffer(Vec<u8>);
+
+    impl EmitSlice for Buffer {
+        type Error = ();
+
+        fn emit_slice(&mut self, s: &[u8]) -> Result<(), ()> {
+            self.0.extend_from_slice(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_brev8() {
+        assert_eq!(0x6875d513, brev8(A0, A1));
+    }
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(0x08c5c533, pack(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_packh() {
+        assert_eq!(0x08c5f533, packh(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_rev8() {
+        let mut buffer = Buffer(Vec::new());
+        Emit::emit_rev8(&mut buffer, A0, A1).unwrap();
+        assert_eq!(0x6985d513_u32.to_le_bytes().to_vec(), buffer.0);
+    }
+
+    #[test]
+    fn test_unzip() {
+        assert_eq!(0x08f5d513, unzip(A0, A1));
+    }
+
+    #[test]
+    fn test_zip() {
+        assert_eq!(0x08f59513, zip(A0, A1));
+    }
+}
@@ -0,0 +1,44 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_xperm4(rd: Register, rs1: Register, rs2: Register) => xperm4;
+        emit_xperm8(rd: Register, rs1: Register, rs2: Register) => xperm8;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn xperm4(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b010, funct7: 0b0010100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn xperm8(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b100, funct7: 0b0010100, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_xperm4() {
+        assert_eq!(0x28c5a533, xperm4(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_xperm8() {
+        assert_eq!(0x28c5c533, xperm8(A0, A1, A2));
+    }
+}
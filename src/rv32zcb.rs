@@ -0,0 +1,222 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_c_lbu(rd: CRegister, base: CRegister, offset: u8) => lbu;
+        emit_c_lh(rd: CRegister, base: CRegister, offset: u8) => lh;
+        emit_c_lhu(rd: CRegister, base: CRegister, offset: u8) => lhu;
+        emit_c_mul(rd: CRegister, rs: CRegister) => mul;
+        emit_c_not(rd: CRegister) => not;
+        emit_c_sb(rs: CRegister, base: CRegister, offset: u8) => sb;
+        emit_c_sext_b(rd: CRegister) => sext_b;
+        emit_c_sext_h(rd: CRegister) => sext_h;
+        emit_c_sh(rs: CRegister, base: CRegister, offset: u8) => sh;
+        emit_c_zext_b(rd: CRegister) => zext_b;
+        emit_c_zext_h(rd: CRegister) => zext_h;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn lbu(rd: CRegister, base: CRegister, offset: u8) -> u16 {
+    assert!(offset < 4);
+    encode!(
+        i6(0b100000),
+        i3(base as u32),
+        i1(offset as u32),
+        i1((offset >> 1) as u32),
+        i3(rd as u32),
+        i2(0b00)
+    ) as u16
+}
+
+#[inline]
+pub fn lhu(rd: CRegister, base: CRegister, offset: u8) -> u16 {
+    assert!(offset == 0 || offset == 2);
+    encode!(
+        i6(0b100001),
+        i3(base as u32),
+        i1(0),
+        i1((offset >> 1) as u32),
+        i3(rd as u32),
+        i2(0b00)
+    ) as u16
+}
+
+#[inline]
+pub fn lh(rd: CRegister, base: CRegister, offset: u8) -> u16 {
+    assert!(offset == 0 || offset == 2);
+    encode!(
+        i6(0b100001),
+        i3(base as u32),
+        i1(1),
+        i1((offset >> 1) as u32),
+        i3(rd as u32),
+        i2(0b00)
+    ) as u16
+}
+
+#[inline]
+pub fn sb(rs: CRegister, base: CRegister, offset: u8) -> u16 {
+    assert!(offset < 4);
+    encode!(
+        i6(0b100010),
+        i3(base as u32),
+        i1(offset as u32),
+        i1((offset >> 1) as u32),
+        i3(rs as u32),
+        i2(0b00)
+    ) as u16
+}
+
+#[inline]
+pub fn sh(rs: CRegister, base: CRegister, offset: u8) -> u16 {
+    assert!(offset == 0 || offset == 2);
+    encode!(
+        i6(0b100011),
+        i3(base as u32),
+        i1(0),
+        i1((offset >> 1) as u32),
+        i3(rs as u32),
+        i2(0b00)
+    ) as u16
+}
+
+#[inline]
+pub fn zext_b(rd: CRegister) -> u16 {
+    encode!(i6(0b100111), i3(rd as u32), i2(0b11), i3(0b000), i2(0b01)) as u16
+}
+
+#[inline]
+pub fn sext_b(rd: CRegister) -> u16 {
+    encode!(i6(0b100111), i3(rd as u32), i2(0b11), i3(0b001), i2(0b01)) as u16
+}
+
+#[inline]
+pub fn zext_h(rd: CRegister) -> u16 {
+    encode!(i6(0b100111), i3(rd as u32), i2(0b11), i3(0b010), i2(0b01)) as u16
+}
+
+#[inline]
+pub fn sext_h(rd: CRegister) -> u16 {
+    encode!(i6(0b100111), i3(rd as u32), i2(0b11), i3(0b011), i2(0b01)) as u16
+}
+
+#[inline]
+pub fn not(rd: CRegister) -> u16 {
+    encode!(i6(0b100111), i3(rd as u32), i2(0b11), i3(0b101), i2(0b01)) as u16
+}
+
+#[inline]
+pub fn mul(rd: CRegister, rs: CRegister) -> u16 {
+    CaType { op: 0b01, funct2: 0b10, funct6: 0b100111, rd, rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CRegister::*;
+
+    #[test]
+    fn test_lbu() {
+        assert_eq!(0x8188, lbu(A0, A1, 0));
+        assert_eq!(0x81c8, lbu(A0, A1, 1));
+        assert_eq!(0x81a8, lbu(A0, A1, 2));
+        assert_eq!(0x81e8, lbu(A0, A1, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lbu_out_of_range() {
+        lbu(A0, A1, 4);
+    }
+
+    #[test]
+    fn test_lh() {
+        assert_eq!(0x85c8, lh(A0, A1, 0));
+        assert_eq!(0x85e8, lh(A0, A1, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lh_unaligned() {
+        lh(A0, A1, 1);
+    }
+
+    #[test]
+    fn test_lhu() {
+        assert_eq!(0x8588, lhu(A0, A1, 0));
+        assert_eq!(0x85a8, lhu(A0, A1, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lhu_unaligned() {
+        lhu(A0, A1, 1);
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(0x9d4d, mul(A0, A1));
+    }
+
+    #[test]
+    fn test_not() {
+        assert_eq!(0x9d75, not(A0));
+    }
+
+    #[test]
+    fn test_sb() {
+        assert_eq!(0x8988, sb(A0, A1, 0));
+        assert_eq!(0x89c8, sb(A0, A1, 1));
+        assert_eq!(0x89a8, sb(A0, A1, 2));
+        assert_eq!(0x89e8, sb(A0, A1, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sb_out_of_range() {
+        sb(A0, A1, 4);
+    }
+
+    #[test]
+    fn test_sext_b() {
+        assert_eq!(0x9d65, sext_b(A0));
+    }
+
+    #[test]
+    fn test_sext_h() {
+        assert_eq!(0x9d6d, sext_h(A0));
+    }
+
+    #[test]
+    fn test_sh() {
+        assert_eq!(0x8d88, sh(A0, A1, 0));
+        assert_eq!(0x8da8, sh(A0, A1, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sh_unaligned() {
+        sh(A0, A1, 1);
+    }
+
+    #[test]
+    fn test_zext_b() {
+        assert_eq!(0x9d61, zext_b(A0));
+    }
+
+    #[test]
+    fn test_zext_h() {
+        assert_eq!(0x9d69, zext_h(A0));
+    }
+}
@@ -0,0 +1,101 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    /// Emits a jump table for `cm.jt` and `cm.jalt` with one entry per label in `targets`.
+    ///
+    /// `address` is the absolute address the table will be loaded at and must be 64-byte aligned.
+    /// Returns the value to write to the `jvt` CSR.
+    fn emit_jump_table<Label>(&mut self, address: u32, targets: &mut [Label]) -> Result<u32, Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        for (entry, label) in table_entries(address, targets.len()).zip(targets.iter_mut()) {
+            self.emit_branch(
+                label,
+                FixupKind::Address32(entry),
+                |e, offset| {
+                    e.emit_slice(&(FixupKind::Address32(entry).apply_fixup_address(offset) as u32).to_le_bytes())
+                },
+            )?;
+        }
+        Ok(address)
+    }
+
+    forward! {
+        emit_cm_jalt(index: u8) => jalt;
+        emit_cm_jt(index: u8) => jt;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+fn table_entries(address: u32, count: usize) -> impl Iterator<Item = u32> {
+    assert!(address & 63 == 0 && count <= 256);
+    (0..count as u32).map(move |index| address + index * 4)
+}
+
+#[inline]
+pub fn jt(index: u8) -> u16 {
+    assert!(index < 32);
+    encode!(i6(0b101000), i8(index as u32), i2(0b10)) as u16
+}
+
+#[inline]
+pub fn jalt(index: u8) -> u16 {
+    assert!(index >= 32);
+    encode!(i6(0b101000), i8(index as u32), i2(0b10)) as u16
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jalt() {
+        assert_eq!(0xa082, jalt(32));
+        assert_eq!(0xa3fe, jalt(255));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jalt_out_of_range() {
+        jalt(31);
+    }
+
+    #[test]
+    fn test_jt() {
+        assert_eq!(0xa002, jt(0));
+        assert_eq!(0xa07e, jt(31));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jt_out_of_range() {
+        jt(32);
+    }
+
+    #[test]
+    fn test_table_entries() {
+        assert_eq!(vec![0x1000, 0x1004, 0x1008], table_entries(0x1000, 3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_table_entries_too_many() {
+        let _ = table_entries(0x1000, 257);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_table_entries_unaligned() {
+        let _ = table_entries(0x1020, 1);
+    }
+}
@@ -0,0 +1,368 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_fcvtmod_w_d(rd: Register, rs: FRegister) => fcvtmod_w_d;
+        emit_fleq_d(rd: Register, rs1: FRegister, rs2: FRegister) => fleq_d;
+        emit_fleq_h(rd: Register, rs1: FRegister, rs2: FRegister) => fleq_h;
+        emit_fleq_q(rd: Register, rs1: FRegister, rs2: FRegister) => fleq_q;
+        emit_fleq_s(rd: Register, rs1: FRegister, rs2: FRegister) => fleq_s;
+        emit_fli_d(rd: FRegister, imm: FliConstant) => fli_d;
+        emit_fli_h(rd: FRegister, imm: FliConstant) => fli_h;
+        emit_fli_q(rd: FRegister, imm: FliConstant) => fli_q;
+        emit_fli_s(rd: FRegister, imm: FliConstant) => fli_s;
+        emit_fltq_d(rd: Register, rs1: FRegister, rs2: FRegister) => fltq_d;
+        emit_fltq_h(rd: Register, rs1: FRegister, rs2: FRegister) => fltq_h;
+        emit_fltq_q(rd: Register, rs1: FRegister, rs2: FRegister) => fltq_q;
+        emit_fltq_s(rd: Register, rs1: FRegister, rs2: FRegister) => fltq_s;
+        emit_fmaxm_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmaxm_d;
+        emit_fmaxm_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmaxm_h;
+        emit_fmaxm_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmaxm_q;
+        emit_fmaxm_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmaxm_s;
+        emit_fminm_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => fminm_d;
+        emit_fminm_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => fminm_h;
+        emit_fminm_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => fminm_q;
+        emit_fminm_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => fminm_s;
+        emit_fmvh_x_d(rd: Register, rs: FRegister) => fmvh_x_d;
+        emit_fmvp_d_x(rd: FRegister, rs1: Register, rs2: Register) => fmvp_d_x;
+        emit_fround_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => fround_d;
+        emit_fround_h(rd: FRegister, rs: FRegister, rm: RoundingMode) => fround_h;
+        emit_fround_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => fround_q;
+        emit_fround_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => fround_s;
+        emit_froundnx_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => froundnx_d;
+        emit_froundnx_h(rd: FRegister, rs: FRegister, rm: RoundingMode) => froundnx_h;
+        emit_froundnx_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => froundnx_q;
+        emit_froundnx_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => froundnx_s;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn fli_h(rd: FRegister, imm: FliConstant) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1111010, rd, rs1: imm as u8, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fli_s(rd: FRegister, imm: FliConstant) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1111000, rd, rs1: imm as u8, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fli_d(rd: FRegister, imm: FliConstant) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1111001, rd, rs1: imm as u8, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fli_q(rd: FRegister, imm: FliConstant) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1111011, rd, rs1: imm as u8, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fminm_h(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010110, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmaxm_h(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b011, funct7: 0b0010110, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fminm_s(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmaxm_s(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b011, funct7: 0b0010100, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fminm_d(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmaxm_d(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b011, funct7: 0b0010101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fminm_q(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmaxm_q(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b011, funct7: 0b0010111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fround_h(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100010, rd, rs1: rs, rs2: 0b00100_u8 }.encode()
+}
+
+#[inline]
+pub fn froundnx_h(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100010, rd, rs1: rs, rs2: 0b00101_u8 }.encode()
+}
+
+#[inline]
+pub fn fround_s(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100000, rd, rs1: rs, rs2: 0b00100_u8 }.encode()
+}
+
+#[inline]
+pub fn froundnx_s(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100000, rd, rs1: rs, rs2: 0b00101_u8 }.encode()
+}
+
+#[inline]
+pub fn fround_d(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100001, rd, rs1: rs, rs2: 0b00100_u8 }.encode()
+}
+
+#[inline]
+pub fn froundnx_d(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100001, rd, rs1: rs, rs2: 0b00101_u8 }.encode()
+}
+
+#[inline]
+pub fn fround_q(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100011, rd, rs1: rs, rs2: 0b00100_u8 }.encode()
+}
+
+#[inline]
+pub fn froundnx_q(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100011, rd, rs1: rs, rs2: 0b00101_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvtmod_w_d(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: RoundingMode::Rtz as u8, funct7: 0b1100001, rd, rs1: rs, rs2: 0b01000_u8 }.encode()
+}
+
+#[inline]
+pub fn fleq_h(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b100, funct7: 0b1010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fltq_h(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b101, funct7: 0b1010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fleq_s(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b100, funct7: 0b1010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fltq_s(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b101, funct7: 0b1010000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fleq_d(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b100, funct7: 0b1010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fltq_d(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b101, funct7: 0b1010001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fleq_q(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b100, funct7: 0b1010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fltq_q(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b101, funct7: 0b1010011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmvh_x_d(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1110001, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fmvp_d_x(rd: FRegister, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1011001, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+    use crate::types::RoundingMode::*;
+
+    #[test]
+    fn test_fcvtmod_w_d() {
+        assert_eq!(0xc2851553, fcvtmod_w_d(A0, Fa0));
+    }
+
+    #[test]
+    fn test_fleq_d() {
+        assert_eq!(0xa2b54553, fleq_d(A0, Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fleq_h() {
+        assert_eq!(0xa4b54553, fleq_h(A0, Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fleq_q() {
+        assert_eq!(0xa6b54553, fleq_q(A0, Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fleq_s() {
+        assert_eq!(0xa0b54553, fleq_s(A0, Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fli_d() {
+        assert_eq!(0xf21f85d3, fli_d(Fa1, FliConstant::Nan));
+        assert_eq!(0xf2160053, fli_d(Ft0, FliConstant::OneHalf));
+    }
+
+    #[test]
+    fn test_fli_h() {
+        assert_eq!(0xf4108553, fli_h(Fa0, FliConstant::Min));
+        assert_eq!(0xf41e80d3, fli_h(Ft1, FliConstant::TwoPow16));
+    }
+
+    #[test]
+    fn test_fli_q() {
+        assert_eq!(0xf61f0653, fli_q(Fa2, FliConstant::Infinity));
+    }
+
+    #[test]
+    fn test_fli_s() {
+        assert_eq!(0xf0180553, fli_s(Fa0, FliConstant::One));
+        assert_eq!(0xf0100dd3, fli_s(Fs11, FliConstant::MinusOne));
+    }
+
+    #[test]
+    fn test_fltq_d() {
+        assert_eq!(0xa31fddd3, fltq_d(S11, Ft11, Fa7));
+    }
+
+    #[test]
+    fn test_fltq_h() {
+        assert_eq!(0xa51fddd3, fltq_h(S11, Ft11, Fa7));
+    }
+
+    #[test]
+    fn test_fltq_q() {
+        assert_eq!(0xa71fddd3, fltq_q(S11, Ft11, Fa7));
+    }
+
+    #[test]
+    fn test_fltq_s() {
+        assert_eq!(0xa11fddd3, fltq_s(S11, Ft11, Fa7));
+    }
+
+    #[test]
+    fn test_fmaxm_d() {
+        assert_eq!(0x2a3130d3, fmaxm_d(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fmaxm_h() {
+        assert_eq!(0x2c3130d3, fmaxm_h(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fmaxm_q() {
+        assert_eq!(0x2e3130d3, fmaxm_q(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fmaxm_s() {
+        assert_eq!(0x283130d3, fmaxm_s(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fminm_d() {
+        assert_eq!(0x2ac5a553, fminm_d(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fminm_h() {
+        assert_eq!(0x2cc5a553, fminm_h(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fminm_q() {
+        assert_eq!(0x2ec5a553, fminm_q(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fminm_s() {
+        assert_eq!(0x28c5a553, fminm_s(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fmvh_x_d() {
+        assert_eq!(0xe2158553, fmvh_x_d(A0, Fa1));
+    }
+
+    #[test]
+    fn test_fmvp_d_x() {
+        assert_eq!(0xb2b50553, fmvp_d_x(Fa0, A0, A1));
+    }
+
+    #[test]
+    fn test_fround_d() {
+        assert_eq!(0x42459553, fround_d(Fa0, Fa1, Rtz));
+    }
+
+    #[test]
+    fn test_fround_h() {
+        assert_eq!(0x44459553, fround_h(Fa0, Fa1, Rtz));
+    }
+
+    #[test]
+    fn test_fround_q() {
+        assert_eq!(0x46459553, fround_q(Fa0, Fa1, Rtz));
+    }
+
+    #[test]
+    fn test_fround_s() {
+        assert_eq!(0x40459553, fround_s(Fa0, Fa1, Rtz));
+    }
+
+    #[test]
+    fn test_froundnx_d() {
+        assert_eq!(0x4254f453, froundnx_d(Fs0, Fs1, Dyn));
+    }
+
+    #[test]
+    fn test_froundnx_h() {
+        assert_eq!(0x4454f453, froundnx_h(Fs0, Fs1, Dyn));
+    }
+
+    #[test]
+    fn test_froundnx_q() {
+        assert_eq!(0x4654f453, froundnx_q(Fs0, Fs1, Dyn));
+    }
+
+    #[test]
+    fn test_froundnx_s() {
+        assert_eq!(0x4054f453, froundnx_s(Fs0, Fs1, Dyn));
+    }
+}
@@ -0,0 +1,331 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32zfhmin;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flh_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        rv32zfhmin::Emit::emit_flh_label(self, rd, rt, label)
+    }
+
+    forward! {
+        emit_fabs_h(rd: FRegister, rs: FRegister) => fabs_h;
+        emit_fadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fadd_h;
+        emit_fclass_h(rd: Register, rs: FRegister) => fclass_h;
+        emit_fcvt_h_w(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_h_w;
+        emit_fcvt_h_wu(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_h_wu;
+        emit_fcvt_w_h(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_w_h;
+        emit_fcvt_wu_h(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_wu_h;
+        emit_fdiv_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fdiv_h;
+        emit_feq_h(rd: Register, rs1: FRegister, rs2: FRegister) => feq_h;
+        emit_fle_h(rd: Register, rs1: FRegister, rs2: FRegister) => fle_h;
+        emit_flt_h(rd: Register, rs1: FRegister, rs2: FRegister) => flt_h;
+        emit_fmadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmadd_h;
+        emit_fmax_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmax_h;
+        emit_fmin_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => fmin_h;
+        emit_fmsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fmsub_h;
+        emit_fmul_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fmul_h;
+        emit_fmv_h(rd: FRegister, rs: FRegister) => fmv_h;
+        emit_fneg_h(rd: FRegister, rs: FRegister) => fneg_h;
+        emit_fnmadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmadd_h;
+        emit_fnmsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => fnmsub_h;
+        emit_fsgnj_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnj_h;
+        emit_fsgnjn_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjn_h;
+        emit_fsgnjx_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => fsgnjx_h;
+        emit_fsqrt_h(rd: FRegister, rs: FRegister, rm: RoundingMode) => fsqrt_h;
+        emit_fsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => fsub_h;
+    }
+
+    forward! {
+        emit_fcvt_d_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_d_h;
+        emit_fcvt_h_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_d;
+        emit_fcvt_h_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_q;
+        emit_fcvt_h_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_s;
+        emit_fcvt_q_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_q_h;
+        emit_fcvt_s_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_s_h;
+        emit_flh(rd: FRegister, base: Register, offset: i16) => rv32zfhmin::flh;
+        emit_fmv_h_x(rd: FRegister, rs: Register) => rv32zfhmin::fmv_h_x;
+        emit_fmv_x_h(rd: Register, rs: FRegister) => rv32zfhmin::fmv_x_h;
+        emit_fsh(rs: FRegister, base: Register, offset: i16) => rv32zfhmin::fsh;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn fmadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Madd, funct2: 0b10, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fmsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Msub, funct2: 0b10, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmsub, funct2: 0b10, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fnmadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode)
+    -> u32
+{
+    R4Type { opcode: Nmadd, funct2: 0b10, funct3: rm as u8, rd, rs1, rs2, rs3 }.encode()
+}
+
+#[inline]
+pub fn fadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0000110, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmul_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fdiv_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0001110, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsqrt_h(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0101110, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fsgnj_h(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjn_h(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fsgnjx_h(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b0010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmin_h(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0010110, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fmax_h(rd: FRegister, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b0010110, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn feq_h(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b010, funct7: 0b1010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn flt_h(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fle_h(rd: Register, rs1: FRegister, rs2: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1010010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn fclass_h(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b001, funct7: 0b1110010, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_w_h(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100010, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_wu_h(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100010, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_h_w(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101010, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_h_wu(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101010, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_h(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnj_h(rd, rs, rs)
+}
+
+#[inline]
+pub fn fneg_h(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjn_h(rd, rs, rs)
+}
+
+#[inline]
+pub fn fabs_h(rd: FRegister, rs: FRegister) -> u32 {
+    fsgnjx_h(rd, rs, rs)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+    use crate::types::RoundingMode::*;
+
+    #[test]
+    fn test_fabs_h() {
+        assert_eq!(0x24b5a553, fabs_h(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fadd_h() {
+        assert_eq!(0x04c58553, fadd_h(Fa0, Fa1, Fa2, Rne));
+    }
+
+    #[test]
+    fn test_fclass_h() {
+        assert_eq!(0xe40b17d3, fclass_h(A5, Fs6));
+    }
+
+    #[test]
+    fn test_fcvt_h_w() {
+        assert_eq!(0xd4050553, fcvt_h_w(Fa0, A0, Rne));
+    }
+
+    #[test]
+    fn test_fcvt_h_wu() {
+        assert_eq!(0xd41fb053, fcvt_h_wu(Ft0, T6, Rup));
+    }
+
+    #[test]
+    fn test_fcvt_w_h() {
+        assert_eq!(0xc4051553, fcvt_w_h(A0, Fa0, Rtz));
+    }
+
+    #[test]
+    fn test_fcvt_wu_h() {
+        assert_eq!(0xc41072d3, fcvt_wu_h(T0, Ft0, Dyn));
+    }
+
+    #[test]
+    fn test_fdiv_h() {
+        assert_eq!(0x1ca4f453, fdiv_h(Fs0, Fs1, Fa0, Dyn));
+    }
+
+    #[test]
+    fn test_feq_h() {
+        assert_eq!(0xa4b52553, feq_h(A0, Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fle_h() {
+        assert_eq!(0xa51f8dd3, fle_h(S11, Ft11, Fa7));
+    }
+
+    #[test]
+    fn test_flt_h() {
+        assert_eq!(0xa4909353, flt_h(T1, Ft1, Fs1));
+    }
+
+    #[test]
+    fn test_fmadd_h() {
+        assert_eq!(0x6cc59543, fmadd_h(Fa0, Fa1, Fa2, Fa3, Rtz));
+    }
+
+    #[test]
+    fn test_fmax_h() {
+        assert_eq!(0x2c3110d3, fmax_h(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fmin_h() {
+        assert_eq!(0x2c3100d3, fmin_h(Ft1, Ft2, Ft3));
+    }
+
+    #[test]
+    fn test_fmsub_h() {
+        assert_eq!(0x1c20f047, fmsub_h(Ft0, Ft1, Ft2, Ft3, Dyn));
+    }
+
+    #[test]
+    fn test_fmul_h() {
+        assert_eq!(0x15b8a053, fmul_h(Ft0, Fa7, Fs11, Rdn));
+    }
+
+    #[test]
+    fn test_fmv_h() {
+        assert_eq!(0x24b58553, fmv_h(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fneg_h() {
+        assert_eq!(0x24b59553, fneg_h(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fnmadd_h() {
+        assert_eq!(0xfdeece4f, fnmadd_h(Ft8, Ft9, Ft10, Ft11, Rmm));
+    }
+
+    #[test]
+    fn test_fnmsub_h() {
+        assert_eq!(0xad49894b, fnmsub_h(Fs2, Fs3, Fs4, Fs5, Rne));
+    }
+
+    #[test]
+    fn test_fsgnj_h() {
+        assert_eq!(0x24c58553, fsgnj_h(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fsgnjn_h() {
+        assert_eq!(0x24c59553, fsgnjn_h(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fsgnjx_h() {
+        assert_eq!(0x24c5a553, fsgnjx_h(Fa0, Fa1, Fa2));
+    }
+
+    #[test]
+    fn test_fsqrt_h() {
+        assert_eq!(0x5c0817d3, fsqrt_h(Fa5, Fa6, Rtz));
+    }
+
+    #[test]
+    fn test_fsub_h() {
+        assert_eq!(0x0cc5b553, fsub_h(Fa0, Fa1, Fa2, Rup));
+    }
+}
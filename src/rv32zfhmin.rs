@@ -0,0 +1,152 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32i::Emit as _;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flh_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        self.emit_branch(
+            label,
+            FixupKind::Load,
+            |e, offset| {
+                let (upper, lower) = to_i20_i12_imm_pair(offset as i32);
+                e.emit_auipc(rt, upper)?;
+                e.emit_flh(rd, rt, lower)
+            },
+        )
+    }
+
+    forward! {
+        emit_fcvt_d_h(rd: FRegister, rs: FRegister) => fcvt_d_h;
+        emit_fcvt_h_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => fcvt_h_d;
+        emit_fcvt_h_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => fcvt_h_q;
+        emit_fcvt_h_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => fcvt_h_s;
+        emit_fcvt_q_h(rd: FRegister, rs: FRegister) => fcvt_q_h;
+        emit_fcvt_s_h(rd: FRegister, rs: FRegister) => fcvt_s_h;
+        emit_flh(rd: FRegister, base: Register, offset: i16) => flh;
+        emit_fmv_h_x(rd: FRegister, rs: Register) => fmv_h_x;
+        emit_fmv_x_h(rd: Register, rs: FRegister) => fmv_x_h;
+        emit_fsh(rs: FRegister, base: Register, offset: i16) => fsh;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+#[inline]
+pub fn flh(rd: FRegister, base: Register, offset: i16) -> u32 {
+    IType { opcode: LoadFp, funct3: 0b001, rd, rs: base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fsh(rs: FRegister, base: Register, offset: i16) -> u32 {
+    SType { opcode: StoreFp, funct3: 0b001, rs, base, imm12: offset }.encode()
+}
+
+#[inline]
+pub fn fmv_x_h(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1110010, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_h_x(rd: FRegister, rs: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1111010, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_s_h(rd: FRegister, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0100000, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_h_s(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100010, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_d_h(rd: FRegister, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0100001, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_h_d(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100010, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_q_h(rd: FRegister, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b0100011, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_h_q(rd: FRegister, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b0100010, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+    use crate::types::RoundingMode::*;
+
+    #[test]
+    fn test_fcvt_d_h() {
+        assert_eq!(0x422100d3, fcvt_d_h(Ft1, Ft2));
+    }
+
+    #[test]
+    fn test_fcvt_h_d() {
+        assert_eq!(0x441170d3, fcvt_h_d(Ft1, Ft2, Dyn));
+    }
+
+    #[test]
+    fn test_fcvt_h_q() {
+        assert_eq!(0x443170d3, fcvt_h_q(Ft1, Ft2, Dyn));
+    }
+
+    #[test]
+    fn test_fcvt_h_s() {
+        assert_eq!(0x44059553, fcvt_h_s(Fa0, Fa1, Rtz));
+    }
+
+    #[test]
+    fn test_fcvt_q_h() {
+        assert_eq!(0x46258553, fcvt_q_h(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_fcvt_s_h() {
+        assert_eq!(0x40258553, fcvt_s_h(Fa0, Fa1));
+    }
+
+    #[test]
+    fn test_flh() {
+        assert_eq!(0xffe11507, flh(Fa0, Sp, -2));
+    }
+
+    #[test]
+    fn test_fmv_h_x() {
+        assert_eq!(0xf4030053, fmv_h_x(Ft0, T1));
+    }
+
+    #[test]
+    fn test_fmv_x_h() {
+        assert_eq!(0xe4058553, fmv_x_h(A0, Fa1));
+    }
+
+    #[test]
+    fn test_fsh() {
+        assert_eq!(0x7e951f27, fsh(Fs1, A0, 2046));
+    }
+}
@@ -0,0 +1,113 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32i;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_select(&mut self, rd: Register, cond: Register, a: Register, b: Register, tmp: Register)
+        -> Result<(), Self::Error>
+    {
+        for word in select(rd, cond, a, b, tmp) {
+            self.emit_slice(&word.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    forward! {
+        emit_czero_eqz(rd: Register, rs1: Register, rs2: Register) => czero_eqz;
+        emit_czero_nez(rd: Register, rs1: Register, rs2: Register) => czero_nez;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn czero_eqz(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b101, funct7: 0b0000111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn czero_nez(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b111, funct7: 0b0000111, rd, rs1, rs2 }.encode()
+}
+
+fn select(rd: Register, cond: Register, a: Register, b: Register, tmp: Register) -> Vec<u32> {
+    assert!(tmp != rd && tmp != cond && tmp != a && tmp != b);
+    if a == b {
+        return vec![rv32i::mv(rd, a)];
+    }
+    // `tmp` holds the `a` half because `rd` may alias any of the sources.
+    vec![czero_eqz(tmp, a, cond), czero_nez(rd, b, cond), rv32i::or(rd, rd, tmp)]
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_czero_eqz() {
+        assert_eq!(0x0ec5d533, czero_eqz(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_czero_nez() {
+        assert_eq!(0x0ec5f533, czero_nez(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(vec![
+            0x0eb652b3, // czero.eqz t0, a2, a1
+            0x0eb6f533, // czero.nez a0, a3, a1
+            0x00556533, // or a0, a0, t0
+        ], select(A0, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_rd_a() {
+        assert_eq!(vec![
+            0x0eb652b3, // czero.eqz t0, a2, a1
+            0x0eb6f633, // czero.nez a2, a3, a1
+            0x00566633, // or a2, a2, t0
+        ], select(A2, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_rd_b() {
+        assert_eq!(vec![
+            0x0eb652b3, // czero.eqz t0, a2, a1
+            0x0eb6f6b3, // czero.nez a3, a3, a1
+            0x0056e6b3, // or a3, a3, t0
+        ], select(A3, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_rd_cond() {
+        assert_eq!(vec![
+            0x0eb652b3, // czero.eqz t0, a2, a1
+            0x0eb6f5b3, // czero.nez a1, a3, a1
+            0x0055e5b3, // or a1, a1, t0
+        ], select(A1, A1, A2, A3, T0));
+    }
+
+    #[test]
+    fn test_select_same() {
+        assert_eq!(vec![
+            0x00060513, // mv a0, a2
+        ], select(A0, A1, A2, A2, T0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_tmp() {
+        select(A0, A1, A2, A3, A1);
+    }
+}
@@ -0,0 +1,99 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_csrc(csr: Csr, rs: Register) => csrc;
+        emit_csrci(csr: Csr, imm: u8) => csrci;
+        emit_csrr(rd: Register, csr: Csr) => csrr;
+        emit_csrrc(rd: Register, csr: Csr, rs: Register) => csrrc;
+        emit_csrrci(rd: Register, csr: Csr, imm: u8) => csrrci;
+        emit_csrrs(rd: Register, csr: Csr, rs: Register) => csrrs;
+        emit_csrrsi(rd: Register, csr: Csr, imm: u8) => csrrsi;
+        emit_csrrw(rd: Register, csr: Csr, rs: Register) => csrrw;
+        emit_csrrwi(rd: Register, csr: Csr, imm: u8) => csrrwi;
+        emit_csrs(csr: Csr, rs: Register) => csrs;
+        emit_csrsi(csr: Csr, imm: u8) => csrsi;
+        emit_csrw(csr: Csr, rs: Register) => csrw;
+        emit_csrwi(csr: Csr, imm: u8) => csrwi;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn csrrw(rd: Register, csr: Csr, rs: Register) -> u32 {
+    assert!(!csr.is_read_only());
+    CsrType { funct3: 0b001, rd, rs, csr }.encode()
+}
+
+#[inline]
+pub fn csrrs(rd: Register, csr: Csr, rs: Register) -> u32 {
+    assert!(rs == Register::Zero || !csr.is_read_only());
+    CsrType { funct3: 0b010, rd, rs, csr }.encode()
+}
+
+#[inline]
+pub fn csrrc(rd: Register, csr: Csr, rs: Register) -> u32 {
+    assert!(rs == Register::Zero || !csr.is_read_only());
+    CsrType { funct3: 0b011, rd, rs, csr }.encode()
+}
+
+#[inline]
+pub fn csrrwi(rd: Register, csr: Csr, imm: u8) -> u32 {
+    assert!(imm < 32 && !csr.is_read_only());
+    CsrType { funct3: 0b101, rd, rs: imm, csr }.encode()
+}
+
+#[inline]
+pub fn csrrsi(rd: Register, csr: Csr, imm: u8) -> u32 {
+    assert!(imm < 32 && (imm == 0 || !csr.is_read_only()));
+    CsrType { funct3: 0b110, rd, rs: imm, csr }.encode()
+}
+
+#[inline]
+pub fn csrrci(rd: Register, csr: Csr, imm: u8) -> u32 {
+    assert!(imm < 32 && (imm == 0 || !csr.is_read_only()));
+    CsrType { funct3: 0b111, rd, rs: imm, csr }.encode()
+}
+
+#[inline]
+pub fn csrr(rd: Register, csr: Csr) -> u32 {
+    csrrs(rd, csr, Register::Zero)
+}
+
+#[inline]
+pub fn csrw(csr: Csr, rs: Register) -> u32 {
+    csrrw(Register::Zero, csr, rs)
+}
+
+#[inline]
+pub fn csrs(csr: Csr, rs: Register) -> u32 {
+    csrrs(Register::Zero, csr, rs)
+}
+
+#[inline]
+pub fn csrc(csr: Csr, rs: Register) -> u32 {
+    csrrc(Register::Zero, csr, rs)
+}
+
+#[inline]
+pub fn csrwi(csr: Csr, imm: u8) -> u32 {
+    csrrwi(Register::Zero, csr, imm)
+}
+
+#[inline]
+pub fn csrsi(csr: Csr, imm: u8) -> u32 {
+    csrrsi(Register::Zero, csr, imm)
+}
+
+#[inline]
+pub fn csrci(csr: Csr, imm: u8) -> u32 {
+    csrrci(Register::Zero, csr, imm)
+}
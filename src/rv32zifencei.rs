@@ -0,0 +1,20 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_fence_i() => fence_i;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn fence_i() -> u32 {
+    IType { opcode: MiscMem, funct3: 0b001, imm12: 0, ..IType::null() }.encode()
+}
@@ -0,0 +1,33 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32i;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_pause() => pause;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn pause() -> u32 {
+    rv32i::fence(FenceSet::W, FenceSet::NONE)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause() {
+        assert_eq!(0x0100000f, pause());
+    }
+}
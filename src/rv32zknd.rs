@@ -0,0 +1,64 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_aes32dsi(rd: Register, rs1: Register, rs2: Register, bs: u8) => aes32dsi;
+        emit_aes32dsmi(rd: Register, rs1: Register, rs2: Register, bs: u8) => aes32dsmi;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn aes32dsi(rd: Register, rs1: Register, rs2: Register, bs: u8) -> u32 {
+    assert!(bs < 4);
+    RType { opcode: Op, funct3: 0b000, funct7: bs << 5 | 0b10101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn aes32dsmi(rd: Register, rs1: Register, rs2: Register, bs: u8) -> u32 {
+    assert!(bs < 4);
+    RType { opcode: Op, funct3: 0b000, funct7: bs << 5 | 0b10111, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_aes32dsi() {
+        assert_eq!(0x2ac58533, aes32dsi(A0, A1, A2, 0));
+        assert_eq!(0x6ac58533, aes32dsi(A0, A1, A2, 1));
+        assert_eq!(0xaac58533, aes32dsi(A0, A1, A2, 2));
+        assert_eq!(0xeac58533, aes32dsi(A0, A1, A2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aes32dsi_out_of_range() {
+        aes32dsi(A0, A1, A2, 4);
+    }
+
+    #[test]
+    fn test_aes32dsmi() {
+        assert_eq!(0x2ec58533, aes32dsmi(A0, A1, A2, 0));
+        assert_eq!(0x6ec58533, aes32dsmi(A0, A1, A2, 1));
+        assert_eq!(0xaec58533, aes32dsmi(A0, A1, A2, 2));
+        assert_eq!(0xeec58533, aes32dsmi(A0, A1, A2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aes32dsmi_out_of_range() {
+        aes32dsmi(A0, A1, A2, 4);
+    }
+}
@@ -0,0 +1,64 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_aes32esi(rd: Register, rs1: Register, rs2: Register, bs: u8) => aes32esi;
+        emit_aes32esmi(rd: Register, rs1: Register, rs2: Register, bs: u8) => aes32esmi;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn aes32esi(rd: Register, rs1: Register, rs2: Register, bs: u8) -> u32 {
+    assert!(bs < 4);
+    RType { opcode: Op, funct3: 0b000, funct7: bs << 5 | 0b10001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn aes32esmi(rd: Register, rs1: Register, rs2: Register, bs: u8) -> u32 {
+    assert!(bs < 4);
+    RType { opcode: Op, funct3: 0b000, funct7: bs << 5 | 0b10011, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_aes32esi() {
+        assert_eq!(0x22c58533, aes32esi(A0, A1, A2, 0));
+        assert_eq!(0x62c58533, aes32esi(A0, A1, A2, 1));
+        assert_eq!(0xa2c58533, aes32esi(A0, A1, A2, 2));
+        assert_eq!(0xe2c58533, aes32esi(A0, A1, A2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aes32esi_out_of_range() {
+        aes32esi(A0, A1, A2, 4);
+    }
+
+    #[test]
+    fn test_aes32esmi() {
+        assert_eq!(0x26c58533, aes32esmi(A0, A1, A2, 0));
+        assert_eq!(0x66c58533, aes32esmi(A0, A1, A2, 1));
+        assert_eq!(0xa6c58533, aes32esmi(A0, A1, A2, 2));
+        assert_eq!(0xe6c58533, aes32esmi(A0, A1, A2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aes32esmi_out_of_range() {
+        aes32esmi(A0, A1, A2, 4);
+    }
+}
@@ -0,0 +1,132 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_sha256sig0(rd: Register, rs: Register) => sha256sig0;
+        emit_sha256sig1(rd: Register, rs: Register) => sha256sig1;
+        emit_sha256sum0(rd: Register, rs: Register) => sha256sum0;
+        emit_sha256sum1(rd: Register, rs: Register) => sha256sum1;
+        emit_sha512sig0h(rd: Register, rs1: Register, rs2: Register) => sha512sig0h;
+        emit_sha512sig0l(rd: Register, rs1: Register, rs2: Register) => sha512sig0l;
+        emit_sha512sig1h(rd: Register, rs1: Register, rs2: Register) => sha512sig1h;
+        emit_sha512sig1l(rd: Register, rs1: Register, rs2: Register) => sha512sig1l;
+        emit_sha512sum0r(rd: Register, rs1: Register, rs2: Register) => sha512sum0r;
+        emit_sha512sum1r(rd: Register, rs1: Register, rs2: Register) => sha512sum1r;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn sha256sig0(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00010, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sha256sig1(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00011, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sha256sum0(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00000, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sha256sum1(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00001, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sha512sig0h(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0101110, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sha512sig0l(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0101010, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sha512sig1h(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0101111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sha512sig1l(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0101011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sha512sum0r(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0101000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sha512sum1r(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0101001, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_sha256sig0() {
+        assert_eq!(0x10259513, sha256sig0(A0, A1));
+    }
+
+    #[test]
+    fn test_sha256sig1() {
+        assert_eq!(0x10359513, sha256sig1(A0, A1));
+    }
+
+    #[test]
+    fn test_sha256sum0() {
+        assert_eq!(0x10059513, sha256sum0(A0, A1));
+    }
+
+    #[test]
+    fn test_sha256sum1() {
+        assert_eq!(0x10159513, sha256sum1(A0, A1));
+    }
+
+    #[test]
+    fn test_sha512sig0h() {
+        assert_eq!(0x5cc58533, sha512sig0h(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_sha512sig0l() {
+        assert_eq!(0x54c58533, sha512sig0l(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_sha512sig1h() {
+        assert_eq!(0x5ec58533, sha512sig1h(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_sha512sig1l() {
+        assert_eq!(0x56c58533, sha512sig1l(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_sha512sum0r() {
+        assert_eq!(0x50c58533, sha512sum0r(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_sha512sum1r() {
+        assert_eq!(0x52c58533, sha512sum1r(A0, A1, A2));
+    }
+}
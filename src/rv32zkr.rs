@@ -0,0 +1,83 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::rv32i::Emit as _;
+use crate::rv32zicsr::Emit as _;
+
+
+pub trait Emit: EmitSlice {
+    /// Branches to `dead` if the entropy source reports `DEAD`. `tmp` is clobbered.
+    fn emit_read_seed<Label>(&mut self, rd: Register, tmp: Register, dead: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        emit_seed_loop(self, rd, tmp, |e| e.emit_bnez_label(tmp, dead))
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+fn emit_seed_loop<E, F>(e: &mut E, rd: Register, tmp: Register, branch_dead: F) -> Result<(), E::Error>
+    where E: EmitSlice + ?Sized,
+          F: FnOnce(&mut E) -> Result<(), E::Error>
+{
+    assert!(rd != tmp);
+    // `seed` must be accessed with a read-write instruction.
+    e.emit_csrrw(rd, Csr::SEED, Register::Zero)?;
+    e.emit_srli(tmp, rd, 30)?;
+    e.emit_addi(tmp, tmp, -(SeedStatus::Es16 as i16))?;
+    e.emit_bltz(tmp, -12)?;
+    branch_dead(e)?;
+    e.emit_slli(rd, rd, 16)?;
+    e.emit_srli(rd, rd, 16)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    struct Buffer(Vec<u8>);
+
+    impl Buffer {
+        fn words(&self) -> Vec<u32> {
+            self.0.chunks(4).map(|w| u32::from_le_bytes([w[0], w[1], w[2], w[3]])).collect()
+        }
+    }
+
+    impl EmitSlice for Buffer {
+        type Error = ();
+
+        fn emit_slice(&mut self, s: &[u8]) -> Result<(), ()> {
+            self.0.extend_from_slice(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_seed() {
+        let mut buffer = Buffer(Vec::new());
+        emit_seed_loop(&mut buffer, A0, T0, |e| e.emit_bnez(T0, 64)).unwrap();
+        assert_eq!(vec![
+            0x01501573, // csrrw a0, seed, zero
+            0x01e55293, // srli t0, a0, 30
+            0xffe28293, // addi t0, t0, -2
+            0xfe02cae3, // bltz t0, -12
+            0x04029063, // bnez t0, 64
+            0x01051513, // slli a0, a0, 16
+            0x01055513, // srli a0, a0, 16
+        ], buffer.words());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_read_seed_alias() {
+        let mut buffer = Buffer(Vec::new());
+        emit_seed_loop(&mut buffer, A0, A0, |e| e.emit_bnez(A0, 64)).unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_sm4ed(rd: Register, rs1: Register, rs2: Register, bs: u8) => sm4ed;
+        emit_sm4ks(rd: Register, rs1: Register, rs2: Register, bs: u8) => sm4ks;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn sm4ed(rd: Register, rs1: Register, rs2: Register, bs: u8) -> u32 {
+    assert!(bs < 4);
+    RType { opcode: Op, funct3: 0b000, funct7: bs << 5 | 0b11000, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn sm4ks(rd: Register, rs1: Register, rs2: Register, bs: u8) -> u32 {
+    assert!(bs < 4);
+    RType { opcode: Op, funct3: 0b000, funct7: bs << 5 | 0b11010, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_sm4ed() {
+        assert_eq!(0x30c58533, sm4ed(A0, A1, A2, 0));
+        assert_eq!(0x70c58533, sm4ed(A0, A1, A2, 1));
+        assert_eq!(0xb0c58533, sm4ed(A0, A1, A2, 2));
+        assert_eq!(0xf0c58533, sm4ed(A0, A1, A2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sm4ed_out_of_range() {
+        sm4ed(A0, A1, A2, 4);
+    }
+
+    #[test]
+    fn test_sm4ks() {
+        assert_eq!(0x34c58533, sm4ks(A0, A1, A2, 0));
+        assert_eq!(0x74c58533, sm4ks(A0, A1, A2, 1));
+        assert_eq!(0xb4c58533, sm4ks(A0, A1, A2, 2));
+        assert_eq!(0xf4c58533, sm4ks(A0, A1, A2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sm4ks_out_of_range() {
+        sm4ks(A0, A1, A2, 4);
+    }
+}
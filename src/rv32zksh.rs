@@ -0,0 +1,44 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_sm3p0(rd: Register, rs: Register) => sm3p0;
+        emit_sm3p1(rd: Register, rs: Register) => sm3p1;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn sm3p0(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_01000, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sm3p1(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_01001, rd, rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_sm3p0() {
+        assert_eq!(0x10859513, sm3p0(A0, A1));
+    }
+
+    #[test]
+    fn test_sm3p1() {
+        assert_eq!(0x10959513, sm3p1(A0, A1));
+    }
+}
@@ -0,0 +1,151 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vandn_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vandn_vv;
+        emit_vandn_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vandn_vx;
+        emit_vbrev8_v(vd: VRegister, vs2: VRegister, vm: VMask) => vbrev8_v;
+        emit_vbrev_v(vd: VRegister, vs2: VRegister, vm: VMask) => vbrev_v;
+        emit_vclz_v(vd: VRegister, vs2: VRegister, vm: VMask) => vclz_v;
+        emit_vcpop_v(vd: VRegister, vs2: VRegister, vm: VMask) => vcpop_v;
+        emit_vctz_v(vd: VRegister, vs2: VRegister, vm: VMask) => vctz_v;
+        emit_vrev8_v(vd: VRegister, vs2: VRegister, vm: VMask) => vrev8_v;
+        emit_vrol_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vrol_vv;
+        emit_vrol_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vrol_vx;
+        emit_vror_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vror_vi;
+        emit_vror_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vror_vv;
+        emit_vror_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vror_vx;
+        emit_vwsll_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => vwsll_vi;
+        emit_vwsll_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vwsll_vv;
+        emit_vwsll_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vwsll_vx;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn vandn_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000001, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vandn_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b000001, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vbrev_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b01010_u8 }.encode()
+}
+
+#[inline]
+pub fn vbrev8_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b01000_u8 }.encode()
+}
+
+#[inline]
+pub fn vrev8_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b01001_u8 }.encode()
+}
+
+#[inline]
+pub fn vclz_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b01100_u8 }.encode()
+}
+
+#[inline]
+pub fn vctz_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b01101_u8 }.encode()
+}
+
+#[inline]
+pub fn vcpop_v(vd: VRegister, vs2: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010010, funct3: 0b010, vm, vd, vs2, rs1: 0b01110_u8 }.encode()
+}
+
+#[inline]
+pub fn vrol_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010101, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vrol_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010101, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vror_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010100, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vror_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b010100, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+/// The rotate amount is a 6-bit immediate whose top bit lives in the funct6 field.
+#[inline]
+pub fn vror_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 64);
+    VArithType { funct6: 0b010100 | imm >> 5, funct3: 0b011, vm, vd, vs2, rs1: imm & 0b11111 }
+        .encode()
+}
+
+#[inline]
+pub fn vwsll_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110101, funct3: 0b000, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vwsll_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b110101, funct3: 0b100, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vwsll_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) -> u32 {
+    assert!(imm < 32);
+    VArithType { funct6: 0b110101, funct3: 0b011, vm, vd, vs2, rs1: imm }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::VRegister::*;
+
+    #[test]
+    fn test_vandn() {
+        assert_eq!(0x070c0457, vandn_vv(V8, V16, V24, VMask::Unmasked));
+        assert_eq!(0x05054457, vandn_vx(V8, V16, A0, VMask::Masked));
+    }
+
+    #[test]
+    fn test_vbrev8() {
+        assert_eq!(0x4b042457, vbrev8_v(V8, V16, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vror_vi() {
+        assert_eq!(0x5301b457, vror_vi(V8, V16, 3, VMask::Unmasked));
+        assert_eq!(0x570fb457, vror_vi(V8, V16, 63, VMask::Unmasked));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vror_vi_out_of_range() {
+        vror_vi(V8, V16, 64, VMask::Unmasked);
+    }
+
+    #[test]
+    fn test_vwsll_vi() {
+        assert_eq!(0xd50fb457, vwsll_vi(V8, V16, 31, VMask::Masked));
+    }
+}
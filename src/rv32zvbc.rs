@@ -0,0 +1,57 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vclmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vclmul_vv;
+        emit_vclmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vclmul_vx;
+        emit_vclmulh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => vclmulh_vv;
+        emit_vclmulh_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => vclmulh_vx;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn vclmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001100, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vclmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001100, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+#[inline]
+pub fn vclmulh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001101, funct3: 0b010, vm, vd, vs2, rs1: vs1 }.encode()
+}
+
+#[inline]
+pub fn vclmulh_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) -> u32 {
+    VArithType { funct6: 0b001101, funct3: 0b110, vm, vd, vs2, rs1 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::VRegister::*;
+
+    #[test]
+    fn test_vclmul() {
+        assert_eq!(0x330c2457, vclmul_vv(V8, V16, V24, VMask::Unmasked));
+    }
+
+    #[test]
+    fn test_vclmulh() {
+        assert_eq!(0x35056457, vclmulh_vx(V8, V16, A0, VMask::Masked));
+    }
+}
@@ -0,0 +1,72 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vghsh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => vghsh_vv;
+        emit_vgmul_vv(vd: VRegister, vs2: VRegister, vtype: VType) => vgmul_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn vghsh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) -> u32 {
+    check_vvv(vd, vs2, vs1, vtype);
+    VeArithType { funct6: 0b101100, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }
+        .encode()
+}
+
+#[inline]
+pub fn vgmul_vv(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b10001_u8 }
+        .encode()
+}
+
+#[inline]
+fn check_vv(vd: VRegister, vs2: VRegister, vtype: VType) {
+    assert!(vtype.sew == Sew::E32);
+    assert!(is_vregister_group(vd, vtype.lmul) && is_vregister_group(vs2, vtype.lmul));
+}
+
+#[inline]
+fn check_vvv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) {
+    check_vv(vd, vs2, vtype);
+    assert!(is_vregister_group(vs1, vtype.lmul));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::VRegister::*;
+
+    #[test]
+    fn test_vghsh() {
+        assert_eq!(0xb30c2477, vghsh_vv(V8, V16, V24, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vghsh_misaligned() {
+        vghsh_vv(V8, V16, V25, VType::new(Sew::E32, Lmul::M2));
+    }
+
+    #[test]
+    fn test_vgmul() {
+        assert_eq!(0xa308a477, vgmul_vv(V8, V16, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vgmul_sew() {
+        vgmul_vv(V8, V16, VType::new(Sew::E64, Lmul::M1));
+    }
+}
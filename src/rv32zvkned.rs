@@ -0,0 +1,159 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vaesdf_vs(vd: VRegister, vs2: VRegister, vtype: VType) => vaesdf_vs;
+        emit_vaesdf_vv(vd: VRegister, vs2: VRegister, vtype: VType) => vaesdf_vv;
+        emit_vaesdm_vs(vd: VRegister, vs2: VRegister, vtype: VType) => vaesdm_vs;
+        emit_vaesdm_vv(vd: VRegister, vs2: VRegister, vtype: VType) => vaesdm_vv;
+        emit_vaesef_vs(vd: VRegister, vs2: VRegister, vtype: VType) => vaesef_vs;
+        emit_vaesef_vv(vd: VRegister, vs2: VRegister, vtype: VType) => vaesef_vv;
+        emit_vaesem_vs(vd: VRegister, vs2: VRegister, vtype: VType) => vaesem_vs;
+        emit_vaesem_vv(vd: VRegister, vs2: VRegister, vtype: VType) => vaesem_vv;
+        emit_vaeskf1_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => vaeskf1_vi;
+        emit_vaeskf2_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => vaeskf2_vi;
+        emit_vaesz_vs(vd: VRegister, vs2: VRegister, vtype: VType) => vaesz_vs;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn vaesdf_vv(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00001_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaesdf_vs(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vs(vd, vs2, vtype);
+    VeArithType { funct6: 0b101001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00001_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaesdm_vv(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00000_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaesdm_vs(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vs(vd, vs2, vtype);
+    VeArithType { funct6: 0b101001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00000_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaesef_vv(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00011_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaesef_vs(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vs(vd, vs2, vtype);
+    VeArithType { funct6: 0b101001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00011_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaesem_vv(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00010_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaesem_vs(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vs(vd, vs2, vtype);
+    VeArithType { funct6: 0b101001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00010_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vaeskf1_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) -> u32 {
+    assert!(rnd < 32);
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b100010, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: rnd }
+        .encode()
+}
+
+#[inline]
+pub fn vaeskf2_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) -> u32 {
+    assert!(rnd < 32);
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101010, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: rnd }
+        .encode()
+}
+
+#[inline]
+pub fn vaesz_vs(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vs(vd, vs2, vtype);
+    VeArithType { funct6: 0b101001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b00111_u8 }
+        .encode()
+}
+
+#[inline]
+fn check_vv(vd: VRegister, vs2: VRegister, vtype: VType) {
+    assert!(vtype.sew == Sew::E32);
+    assert!(is_vregister_group(vd, vtype.lmul) && is_vregister_group(vs2, vtype.lmul));
+}
+
+/// `vs2` holds a single element group that is applied to every element group of `vd`.
+#[inline]
+fn check_vs(vd: VRegister, vs2: VRegister, vtype: VType) {
+    assert!(vtype.sew == Sew::E32);
+    assert!(is_vregister_group(vd, vtype.lmul) && !is_vregister_overlap(vd, vtype.lmul, vs2, Lmul::M1));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::VRegister::*;
+
+    #[test]
+    fn test_vaesdf() {
+        assert_eq!(0xa300a477, vaesdf_vv(V8, V16, VType::new(Sew::E32, Lmul::M1)));
+        assert_eq!(0xa700a477, vaesdf_vs(V8, V16, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    fn test_vaeskf1() {
+        assert_eq!(0x8b052477, vaeskf1_vi(V8, V16, 10, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vaeskf1_misaligned() {
+        vaeskf1_vi(V9, V16, 10, VType::new(Sew::E32, Lmul::M2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vaeskf1_sew() {
+        vaeskf1_vi(V8, V16, 10, VType::new(Sew::E64, Lmul::M1));
+    }
+
+    #[test]
+    fn test_vaesz() {
+        assert_eq!(0xa703a477, vaesz_vs(V8, V16, VType::new(Sew::E32, Lmul::M4)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vaesz_overlap() {
+        vaesz_vs(V8, V11, VType::new(Sew::E32, Lmul::M4));
+    }
+}
@@ -0,0 +1,89 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vsha2ch_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => vsha2ch_vv;
+        emit_vsha2cl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => vsha2cl_vv;
+        emit_vsha2ms_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => vsha2ms_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn vsha2ch_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) -> u32 {
+    check_vvv(vd, vs2, vs1, vtype);
+    VeArithType { funct6: 0b101110, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }
+        .encode()
+}
+
+#[inline]
+pub fn vsha2cl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) -> u32 {
+    check_vvv(vd, vs2, vs1, vtype);
+    VeArithType { funct6: 0b101111, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }
+        .encode()
+}
+
+#[inline]
+pub fn vsha2ms_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) -> u32 {
+    check_vvv(vd, vs2, vs1, vtype);
+    VeArithType { funct6: 0b101101, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }
+        .encode()
+}
+
+/// SEW=32 selects SHA-256 (Zvknha) and SEW=64 selects SHA-512 (Zvknhb).
+#[inline]
+fn check_vvv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) {
+    let lmul = vtype.lmul;
+    assert!(vtype.sew == Sew::E32 || vtype.sew == Sew::E64);
+    assert!(is_vregister_group(vd, lmul) && is_vregister_group(vs2, lmul) && is_vregister_group(vs1, lmul));
+    assert!(!is_vregister_overlap(vd, lmul, vs2, lmul) && !is_vregister_overlap(vd, lmul, vs1, lmul));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::VRegister::*;
+
+    #[test]
+    fn test_vsha2ch() {
+        assert_eq!(0xbb0c2477, vsha2ch_vv(V8, V16, V24, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsha2ch_misaligned() {
+        vsha2ch_vv(V8, V17, V24, VType::new(Sew::E32, Lmul::M2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsha2ch_overlap() {
+        vsha2ch_vv(V8, V16, V8, VType::new(Sew::E32, Lmul::M1));
+    }
+
+    #[test]
+    fn test_vsha2ms() {
+        assert_eq!(0xb70c2477, vsha2ms_vv(V8, V16, V24, VType::new(Sew::E64, Lmul::M2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsha2ms_overlap() {
+        vsha2ms_vv(V8, V9, V24, VType::new(Sew::E64, Lmul::M2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsha2ms_sew() {
+        vsha2ms_vv(V8, V16, V24, VType::new(Sew::E16, Lmul::M1));
+    }
+}
@@ -0,0 +1,82 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vsm4k_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => vsm4k_vi;
+        emit_vsm4r_vs(vd: VRegister, vs2: VRegister, vtype: VType) => vsm4r_vs;
+        emit_vsm4r_vv(vd: VRegister, vs2: VRegister, vtype: VType) => vsm4r_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn vsm4k_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) -> u32 {
+    assert!(rnd < 32);
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b100001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: rnd }
+        .encode()
+}
+
+#[inline]
+pub fn vsm4r_vv(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b10000_u8 }
+        .encode()
+}
+
+#[inline]
+pub fn vsm4r_vs(vd: VRegister, vs2: VRegister, vtype: VType) -> u32 {
+    check_vs(vd, vs2, vtype);
+    VeArithType { funct6: 0b101001, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: 0b10000_u8 }
+        .encode()
+}
+
+#[inline]
+fn check_vv(vd: VRegister, vs2: VRegister, vtype: VType) {
+    assert!(vtype.sew == Sew::E32);
+    assert!(is_vregister_group(vd, vtype.lmul) && is_vregister_group(vs2, vtype.lmul));
+}
+
+/// `vs2` holds a single element group that is applied to every element group of `vd`.
+#[inline]
+fn check_vs(vd: VRegister, vs2: VRegister, vtype: VType) {
+    assert!(vtype.sew == Sew::E32);
+    assert!(is_vregister_group(vd, vtype.lmul) && !is_vregister_overlap(vd, vtype.lmul, vs2, Lmul::M1));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::VRegister::*;
+
+    #[test]
+    fn test_vsm4k() {
+        assert_eq!(0x8703a477, vsm4k_vi(V8, V16, 7, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsm4k_sew() {
+        vsm4k_vi(V8, V16, 7, VType::new(Sew::E8, Lmul::M1));
+    }
+
+    #[test]
+    fn test_vsm4r() {
+        assert_eq!(0xa7082477, vsm4r_vs(V8, V16, VType::new(Sew::E32, Lmul::M2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsm4r_vs_overlap() {
+        vsm4r_vs(V8, V9, VType::new(Sew::E32, Lmul::M2));
+    }
+}
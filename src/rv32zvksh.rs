@@ -0,0 +1,80 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vsm3c_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => vsm3c_vi;
+        emit_vsm3me_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => vsm3me_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn vsm3c_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) -> u32 {
+    assert!(rnd < 32);
+    check_vv(vd, vs2, vtype);
+    VeArithType { funct6: 0b101011, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: rnd }
+        .encode()
+}
+
+#[inline]
+pub fn vsm3me_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) -> u32 {
+    check_vvv(vd, vs2, vs1, vtype);
+    VeArithType { funct6: 0b100000, funct3: 0b010, vm: VMask::Unmasked, vd, vs2, rs1: vs1 }
+        .encode()
+}
+
+#[inline]
+fn check_vv(vd: VRegister, vs2: VRegister, vtype: VType) {
+    let lmul = vtype.lmul;
+    assert!(vtype.sew == Sew::E32);
+    assert!(is_vregister_group(vd, lmul) && is_vregister_group(vs2, lmul) && !is_vregister_overlap(vd, lmul, vs2, lmul));
+}
+
+#[inline]
+fn check_vvv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) {
+    check_vv(vd, vs2, vtype);
+    assert!(is_vregister_group(vs1, vtype.lmul));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::VRegister::*;
+
+    #[test]
+    fn test_vsm3c() {
+        assert_eq!(0xaf0fa477, vsm3c_vi(V8, V16, 31, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsm3c_overlap() {
+        vsm3c_vi(V8, V8, 0, VType::new(Sew::E32, Lmul::M1));
+    }
+
+    #[test]
+    fn test_vsm3me() {
+        assert_eq!(0x830c2477, vsm3me_vv(V8, V16, V24, VType::new(Sew::E32, Lmul::M1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsm3me_misaligned() {
+        vsm3me_vv(V8, V16, V26, VType::new(Sew::E32, Lmul::M4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vsm3me_sew() {
+        vsm3me_vv(V8, V16, V24, VType::new(Sew::E64, Lmul::M1));
+    }
+}
@@ -0,0 +1,97 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32a;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_amoadd_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoadd_d;
+        emit_amoand_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoand_d;
+        emit_amomax_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomax_d;
+        emit_amomaxu_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomaxu_d;
+        emit_amomin_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amomin_d;
+        emit_amominu_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amominu_d;
+        emit_amoor_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoor_d;
+        emit_amoswap_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoswap_d;
+        emit_amoxor_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amoxor_d;
+        emit_lr_d(rd: Register, base: Register, ordering: AmoOrdering) => lr_d;
+        emit_sc_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => sc_d;
+    }
+
+    forward! {
+        emit_amoadd_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amoadd_w;
+        emit_amoand_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amoand_w;
+        emit_amomax_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amomax_w;
+        emit_amomaxu_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amomaxu_w;
+        emit_amomin_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amomin_w;
+        emit_amominu_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amominu_w;
+        emit_amoor_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amoor_w;
+        emit_amoswap_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amoswap_w;
+        emit_amoxor_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::amoxor_w;
+        emit_lr_w(rd: Register, base: Register, ordering: AmoOrdering) => rv32a::lr_w;
+        emit_sc_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32a::sc_w;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn lr_d(rd: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b00010, ordering, rd, rs1: base, rs2: Register::Zero }.encode()
+}
+
+#[inline]
+pub fn sc_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b00011, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoadd_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b00000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoswap_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b00001, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoxor_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b00100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoor_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b01000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amoand_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b01100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomin_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b10000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomax_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b10100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amominu_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b11000, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amomaxu_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b11100, ordering, rd, rs1: base, rs2: rs }.encode()
+}
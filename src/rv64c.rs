@@ -75,6 +75,10 @@ pub trait Emit: EmitSlice {
         emit_c_beqz(rs: CRegister, offset: i16) => rv32c::beqz;
         emit_c_bnez(rs: CRegister, offset: i16) => rv32c::bnez;
         emit_c_ebreak() => rv32c::ebreak;
+        emit_c_fld(rd: CFRegister, base: CRegister, offset: u8) => rv32c::fld;
+        emit_c_fldsp(rd: FRegister, offset: u16) => rv32c::fldsp;
+        emit_c_fsd(rs: CFRegister, base: CRegister, offset: u8) => rv32c::fsd;
+        emit_c_fsdsp(rs: FRegister, offset: u16) => rv32c::fsdsp;
         emit_c_j(offset: i16) => rv32c::j;
         emit_c_jalr(rs: Register) => rv32c::jalr;
         emit_c_jr(rs: Register) => rv32c::jr;
@@ -0,0 +1,93 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32d;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_fld_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        rv32d::Emit::emit_fld_label(self, rd, rt, label)
+    }
+
+    forward! {
+        emit_fcvt_d_l(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_d_l;
+        emit_fcvt_d_lu(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_d_lu;
+        emit_fcvt_l_d(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_l_d;
+        emit_fcvt_lu_d(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_lu_d;
+        emit_fmv_d_x(rd: FRegister, rs: Register) => fmv_d_x;
+        emit_fmv_x_d(rd: Register, rs: FRegister) => fmv_x_d;
+    }
+
+    forward! {
+        emit_fabs_d(rd: FRegister, rs: FRegister) => rv32d::fabs_d;
+        emit_fadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32d::fadd_d;
+        emit_fclass_d(rd: Register, rs: FRegister) => rv32d::fclass_d;
+        emit_fcvt_d_s(rd: FRegister, rs: FRegister) => rv32d::fcvt_d_s;
+        emit_fcvt_d_w(rd: FRegister, rs: Register) => rv32d::fcvt_d_w;
+        emit_fcvt_d_wu(rd: FRegister, rs: Register) => rv32d::fcvt_d_wu;
+        emit_fcvt_s_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32d::fcvt_s_d;
+        emit_fcvt_w_d(rd: Register, rs: FRegister, rm: RoundingMode) => rv32d::fcvt_w_d;
+        emit_fcvt_wu_d(rd: Register, rs: FRegister, rm: RoundingMode) => rv32d::fcvt_wu_d;
+        emit_fdiv_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32d::fdiv_d;
+        emit_feq_d(rd: Register, rs1: FRegister, rs2: FRegister) => rv32d::feq_d;
+        emit_fld(rd: FRegister, base: Register, offset: i16) => rv32d::fld;
+        emit_fle_d(rd: Register, rs1: FRegister, rs2: FRegister) => rv32d::fle_d;
+        emit_flt_d(rd: Register, rs1: FRegister, rs2: FRegister) => rv32d::flt_d;
+        emit_fmadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32d::fmadd_d;
+        emit_fmax_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32d::fmax_d;
+        emit_fmin_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32d::fmin_d;
+        emit_fmsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32d::fmsub_d;
+        emit_fmul_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32d::fmul_d;
+        emit_fmv_d(rd: FRegister, rs: FRegister) => rv32d::fmv_d;
+        emit_fneg_d(rd: FRegister, rs: FRegister) => rv32d::fneg_d;
+        emit_fnmadd_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32d::fnmadd_d;
+        emit_fnmsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32d::fnmsub_d;
+        emit_fsd(rs: FRegister, base: Register, offset: i16) => rv32d::fsd;
+        emit_fsgnj_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32d::fsgnj_d;
+        emit_fsgnjn_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32d::fsgnjn_d;
+        emit_fsgnjx_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32d::fsgnjx_d;
+        emit_fsqrt_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32d::fsqrt_d;
+        emit_fsub_d(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32d::fsub_d;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+#[inline]
+pub fn fcvt_l_d(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100001, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_lu_d(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100001, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_x_d(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1110001, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_d_l(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101001, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_d_lu(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101001, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn fmv_d_x(rd: FRegister, rs: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1111001, rd, rs1: rs, rs2: 0b00000_u8 }.encode()
+}
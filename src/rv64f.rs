@@ -0,0 +1,82 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32f;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flw_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        rv32f::Emit::emit_flw_label(self, rd, rt, label)
+    }
+
+    forward! {
+        emit_fcvt_l_s(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_l_s;
+        emit_fcvt_lu_s(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_lu_s;
+        emit_fcvt_s_l(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_s_l;
+        emit_fcvt_s_lu(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_s_lu;
+    }
+
+    forward! {
+        emit_fabs_s(rd: FRegister, rs: FRegister) => rv32f::fabs_s;
+        emit_fadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32f::fadd_s;
+        emit_fclass_s(rd: Register, rs: FRegister) => rv32f::fclass_s;
+        emit_fcvt_s_w(rd: FRegister, rs: Register, rm: RoundingMode) => rv32f::fcvt_s_w;
+        emit_fcvt_s_wu(rd: FRegister, rs: Register, rm: RoundingMode) => rv32f::fcvt_s_wu;
+        emit_fcvt_w_s(rd: Register, rs: FRegister, rm: RoundingMode) => rv32f::fcvt_w_s;
+        emit_fcvt_wu_s(rd: Register, rs: FRegister, rm: RoundingMode) => rv32f::fcvt_wu_s;
+        emit_fdiv_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32f::fdiv_s;
+        emit_feq_s(rd: Register, rs1: FRegister, rs2: FRegister) => rv32f::feq_s;
+        emit_fle_s(rd: Register, rs1: FRegister, rs2: FRegister) => rv32f::fle_s;
+        emit_flt_s(rd: Register, rs1: FRegister, rs2: FRegister) => rv32f::flt_s;
+        emit_flw(rd: FRegister, base: Register, offset: i16) => rv32f::flw;
+        emit_fmadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32f::fmadd_s;
+        emit_fmax_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32f::fmax_s;
+        emit_fmin_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32f::fmin_s;
+        emit_fmsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32f::fmsub_s;
+        emit_fmul_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32f::fmul_s;
+        emit_fmv_s(rd: FRegister, rs: FRegister) => rv32f::fmv_s;
+        emit_fmv_w_x(rd: FRegister, rs: Register) => rv32f::fmv_w_x;
+        emit_fmv_x_w(rd: Register, rs: FRegister) => rv32f::fmv_x_w;
+        emit_fneg_s(rd: FRegister, rs: FRegister) => rv32f::fneg_s;
+        emit_fnmadd_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32f::fnmadd_s;
+        emit_fnmsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32f::fnmsub_s;
+        emit_fsgnj_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32f::fsgnj_s;
+        emit_fsgnjn_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32f::fsgnjn_s;
+        emit_fsgnjx_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32f::fsgnjx_s;
+        emit_fsqrt_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32f::fsqrt_s;
+        emit_fsub_s(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32f::fsub_s;
+        emit_fsw(rs: FRegister, base: Register, offset: i16) => rv32f::fsw;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn fcvt_l_s(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100000, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_lu_s(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100000, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_s_l(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101000, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_s_lu(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101000, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
@@ -0,0 +1,74 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32h;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_hlv_d(rd: Register, base: Register) => hlv_d;
+        emit_hlv_wu(rd: Register, base: Register) => hlv_wu;
+        emit_hsv_d(rs: Register, base: Register) => hsv_d;
+    }
+
+    forward! {
+        emit_hfence_gvma(gaddr: Option<Register>, vmid: Option<Register>) => rv32h::hfence_gvma;
+        emit_hfence_vvma(vaddr: Option<Register>, asid: Option<Register>) => rv32h::hfence_vvma;
+        emit_hinval_gvma(gaddr: Option<Register>, vmid: Option<Register>) => rv32h::hinval_gvma;
+        emit_hinval_vvma(vaddr: Option<Register>, asid: Option<Register>) => rv32h::hinval_vvma;
+        emit_hlv_b(rd: Register, base: Register) => rv32h::hlv_b;
+        emit_hlv_bu(rd: Register, base: Register) => rv32h::hlv_bu;
+        emit_hlv_h(rd: Register, base: Register) => rv32h::hlv_h;
+        emit_hlv_hu(rd: Register, base: Register) => rv32h::hlv_hu;
+        emit_hlv_w(rd: Register, base: Register) => rv32h::hlv_w;
+        emit_hlvx_hu(rd: Register, base: Register) => rv32h::hlvx_hu;
+        emit_hlvx_wu(rd: Register, base: Register) => rv32h::hlvx_wu;
+        emit_hsv_b(rs: Register, base: Register) => rv32h::hsv_b;
+        emit_hsv_h(rs: Register, base: Register) => rv32h::hsv_h;
+        emit_hsv_w(rs: Register, base: Register) => rv32h::hsv_w;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn hlv_wu(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110100, rd, rs1: base, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn hlv_d(rd: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110110, rd, rs1: base, rs2: 0b00000_u8 }.encode()
+}
+
+#[inline]
+pub fn hsv_d(rs: Register, base: Register) -> u32 {
+    RType { opcode: System, funct3: 0b100, funct7: 0b0110111, rd: Register::Zero, rs1: base, rs2: rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_hlv_d() {
+        assert_eq!(0x6c05c573, hlv_d(A0, A1));
+    }
+
+    #[test]
+    fn test_hlv_wu() {
+        assert_eq!(0x6815c573, hlv_wu(A0, A1));
+    }
+
+    #[test]
+    fn test_hsv_d() {
+        assert_eq!(0x6ea5c073, hsv_d(A0, A1));
+    }
+}
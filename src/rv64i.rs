@@ -398,6 +398,12 @@ pub trait Emit: EmitSlice {
         )
     }
 
+    fn emit_select_mask(&mut self, rd: Register, cond: Register, a: Register, b: Register, tmp: Register)
+        -> Result<(), Self::Error>
+    {
+        rv32i::Emit::emit_select_mask(self, rd, cond, a, b, tmp)
+    }
+
     forward! {
         emit_addiw(rd: Register, rs: Register, imm12: i16) => addiw;
         emit_addw(rd: Register, rs1: Register, rs2: Register) => addw;
@@ -443,6 +449,8 @@ pub trait Emit: EmitSlice {
         emit_bnez(rs: Register, offset: i16) => rv32i::bnez;
         emit_ebreak() => rv32i::ebreak;
         emit_ecall() => rv32i::ecall;
+        emit_fence(pred: FenceSet, succ: FenceSet) => rv32i::fence;
+        emit_fence_tso() => rv32i::fence_tso;
         emit_j(offset: i32) => rv32i::j;
         emit_jal(rd: Register, offset: i32) => rv32i::jal;
         emit_jalr(rd: Register, base: Register, offset: i16) => rv32i::jalr;
@@ -0,0 +1,23 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32priv;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_dret() => rv32priv::dret;
+        emit_mret() => rv32priv::mret;
+        emit_sfence_inval_ir() => rv32priv::sfence_inval_ir;
+        emit_sfence_vma(vaddr: Option<Register>, asid: Option<Register>) => rv32priv::sfence_vma;
+        emit_sfence_w_inval() => rv32priv::sfence_w_inval;
+        emit_sinval_vma(vaddr: Option<Register>, asid: Option<Register>) => rv32priv::sinval_vma;
+        emit_sret() => rv32priv::sret;
+        emit_wfi() => rv32priv::wfi;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
@@ -0,0 +1,122 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32i::Emit as _;
+
+use crate::rv32q;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flq_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        self.emit_branch(
+            label,
+            FixupKind::Load,
+            |e, offset| {
+                let (upper, lower) = to_i20_i12_imm_pair(offset as i32);
+                e.emit_auipc(rt, upper)?;
+                e.emit_flq(rd, rt, lower)
+            },
+        )
+    }
+
+    forward! {
+        emit_fcvt_l_q(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_l_q;
+        emit_fcvt_lu_q(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_lu_q;
+        emit_fcvt_q_l(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_q_l;
+        emit_fcvt_q_lu(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_q_lu;
+    }
+
+    forward! {
+        emit_fabs_q(rd: FRegister, rs: FRegister) => rv32q::fabs_q;
+        emit_fadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32q::fadd_q;
+        emit_fclass_q(rd: Register, rs: FRegister) => rv32q::fclass_q;
+        emit_fcvt_d_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32q::fcvt_d_q;
+        emit_fcvt_q_d(rd: FRegister, rs: FRegister) => rv32q::fcvt_q_d;
+        emit_fcvt_q_s(rd: FRegister, rs: FRegister) => rv32q::fcvt_q_s;
+        emit_fcvt_q_w(rd: FRegister, rs: Register) => rv32q::fcvt_q_w;
+        emit_fcvt_q_wu(rd: FRegister, rs: Register) => rv32q::fcvt_q_wu;
+        emit_fcvt_s_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32q::fcvt_s_q;
+        emit_fcvt_w_q(rd: Register, rs: FRegister, rm: RoundingMode) => rv32q::fcvt_w_q;
+        emit_fcvt_wu_q(rd: Register, rs: FRegister, rm: RoundingMode) => rv32q::fcvt_wu_q;
+        emit_fdiv_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32q::fdiv_q;
+        emit_feq_q(rd: Register, rs1: FRegister, rs2: FRegister) => rv32q::feq_q;
+        emit_fle_q(rd: Register, rs1: FRegister, rs2: FRegister) => rv32q::fle_q;
+        emit_flq(rd: FRegister, base: Register, offset: i16) => rv32q::flq;
+        emit_flt_q(rd: Register, rs1: FRegister, rs2: FRegister) => rv32q::flt_q;
+        emit_fmadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32q::fmadd_q;
+        emit_fmax_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32q::fmax_q;
+        emit_fmin_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32q::fmin_q;
+        emit_fmsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32q::fmsub_q;
+        emit_fmul_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32q::fmul_q;
+        emit_fmv_q(rd: FRegister, rs: FRegister) => rv32q::fmv_q;
+        emit_fneg_q(rd: FRegister, rs: FRegister) => rv32q::fneg_q;
+        emit_fnmadd_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32q::fnmadd_q;
+        emit_fnmsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32q::fnmsub_q;
+        emit_fsgnj_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32q::fsgnj_q;
+        emit_fsgnjn_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32q::fsgnjn_q;
+        emit_fsgnjx_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32q::fsgnjx_q;
+        emit_fsq(rs: FRegister, base: Register, offset: i16) => rv32q::fsq;
+        emit_fsqrt_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32q::fsqrt_q;
+        emit_fsub_q(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32q::fsub_q;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+#[inline]
+pub fn fcvt_l_q(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100011, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_lu_q(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100011, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_q_l(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101011, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_q_lu(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101011, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+    use crate::types::RoundingMode::*;
+
+    #[test]
+    fn test_fcvt_l_q() {
+        assert_eq!(0xc6251553, fcvt_l_q(A0, Fa0, Rtz));
+    }
+
+    #[test]
+    fn test_fcvt_lu_q() {
+        assert_eq!(0xc63072d3, fcvt_lu_q(T0, Ft0, Dyn));
+    }
+
+    #[test]
+    fn test_fcvt_q_l() {
+        assert_eq!(0xd6250553, fcvt_q_l(Fa0, A0, Rne));
+    }
+
+    #[test]
+    fn test_fcvt_q_lu() {
+        assert_eq!(0xd63fb053, fcvt_q_lu(Ft0, T6, Rup));
+    }
+}
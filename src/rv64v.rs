@@ -0,0 +1,424 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32v;
+
+
+#[allow(clippy::too_many_arguments)]
+pub trait Emit: EmitSlice {
+    fn emit_strip_mine<Label, F>(
+        &mut self,
+        head: &mut Label,
+        n: Register,
+        vl: Register,
+        vtype: VType,
+        pointers: &[Register],
+        body: F,
+    ) -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>,
+          F: FnOnce(&mut Self) -> Result<(), Self::Error>
+    {
+        rv32v::Emit::emit_strip_mine(self, head, n, vl, vtype, pointers, body)
+    }
+
+    fn emit_vmemcpy(
+        &mut self,
+        dst: Register,
+        src: Register,
+        n: Register,
+        vl: Register,
+        v: VRegister,
+    ) -> Result<(), Self::Error> {
+        rv32v::Emit::emit_vmemcpy(self, dst, src, n, vl, v)
+    }
+
+    fn emit_vmemset(
+        &mut self,
+        dst: Register,
+        value: Register,
+        n: Register,
+        vl: Register,
+        v: VRegister,
+    ) -> Result<(), Self::Error> {
+        rv32v::Emit::emit_vmemset(self, dst, value, n, vl, v)
+    }
+
+    fn emit_vstrlen(
+        &mut self,
+        s: Register,
+        len: Register,
+        vl: Register,
+        index: Register,
+        v: VRegister,
+    ) -> Result<(), Self::Error> {
+        rv32v::Emit::emit_vstrlen(self, s, len, vl, index, v)
+    }
+
+    fn emit_vfdot_s(
+        &mut self,
+        result: FRegister,
+        x: Register,
+        y: Register,
+        n: Register,
+        vl: Register,
+        acc: VRegister,
+        vx: VRegister,
+        vy: VRegister,
+    ) -> Result<(), Self::Error> {
+        rv32v::Emit::emit_vfdot_s(self, result, x, y, n, vl, acc, vx, vy)
+    }
+
+    forward! {
+        emit_vaadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vaadd_vv;
+        emit_vaadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vaadd_vx;
+        emit_vaaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vaaddu_vv;
+        emit_vaaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vaaddu_vx;
+        emit_vadc_vim(vd: VRegister, vs2: VRegister, imm: i8) => rv32v::vadc_vim;
+        emit_vadc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vadc_vvm;
+        emit_vadc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => rv32v::vadc_vxm;
+        emit_vadd_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vadd_vi;
+        emit_vadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vadd_vv;
+        emit_vadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vadd_vx;
+        emit_vand_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vand_vi;
+        emit_vand_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vand_vv;
+        emit_vand_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vand_vx;
+        emit_vasub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vasub_vv;
+        emit_vasub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vasub_vx;
+        emit_vasubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vasubu_vv;
+        emit_vasubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vasubu_vx;
+        emit_vcompress_vm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vcompress_vm;
+        emit_vcpop_m(rd: Register, vs2: VRegister, vm: VMask) => rv32v::vcpop_m;
+        emit_vdiv_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vdiv_vv;
+        emit_vdiv_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vdiv_vx;
+        emit_vdivu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vdivu_vv;
+        emit_vdivu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vdivu_vx;
+        emit_vfabs_v(vd: VRegister, vs: VRegister, vm: VMask) => rv32v::vfabs_v;
+        emit_vfadd_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfadd_vf;
+        emit_vfadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfadd_vv;
+        emit_vfclass_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfclass_v;
+        emit_vfcvt_f_x_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfcvt_f_x_v;
+        emit_vfcvt_f_xu_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfcvt_f_xu_v;
+        emit_vfcvt_rtz_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfcvt_rtz_x_f_v;
+        emit_vfcvt_rtz_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfcvt_rtz_xu_f_v;
+        emit_vfcvt_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfcvt_x_f_v;
+        emit_vfcvt_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfcvt_xu_f_v;
+        emit_vfdiv_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfdiv_vf;
+        emit_vfdiv_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfdiv_vv;
+        emit_vfirst_m(rd: Register, vs2: VRegister, vm: VMask) => rv32v::vfirst_m;
+        emit_vfmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfmacc_vf;
+        emit_vfmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfmacc_vv;
+        emit_vfmadd_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfmadd_vf;
+        emit_vfmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfmadd_vv;
+        emit_vfmax_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfmax_vf;
+        emit_vfmax_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfmax_vv;
+        emit_vfmerge_vfm(vd: VRegister, vs2: VRegister, rs1: FRegister) => rv32v::vfmerge_vfm;
+        emit_vfmin_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfmin_vf;
+        emit_vfmin_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfmin_vv;
+        emit_vfmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfmsac_vf;
+        emit_vfmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfmsac_vv;
+        emit_vfmsub_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfmsub_vf;
+        emit_vfmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfmsub_vv;
+        emit_vfmul_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfmul_vf;
+        emit_vfmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfmul_vv;
+        emit_vfmv_f_s(rd: FRegister, vs2: VRegister) => rv32v::vfmv_f_s;
+        emit_vfmv_s_f(vd: VRegister, rs1: FRegister) => rv32v::vfmv_s_f;
+        emit_vfmv_v_f(vd: VRegister, rs1: FRegister) => rv32v::vfmv_v_f;
+        emit_vfncvt_f_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_f_f_w;
+        emit_vfncvt_f_x_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_f_x_w;
+        emit_vfncvt_f_xu_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_f_xu_w;
+        emit_vfncvt_rod_f_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_rod_f_f_w;
+        emit_vfncvt_rtz_x_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_rtz_x_f_w;
+        emit_vfncvt_rtz_xu_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_rtz_xu_f_w;
+        emit_vfncvt_x_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_x_f_w;
+        emit_vfncvt_xu_f_w(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfncvt_xu_f_w;
+        emit_vfneg_v(vd: VRegister, vs: VRegister, vm: VMask) => rv32v::vfneg_v;
+        emit_vfnmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmacc_vf;
+        emit_vfnmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmacc_vv;
+        emit_vfnmadd_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmadd_vf;
+        emit_vfnmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmadd_vv;
+        emit_vfnmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmsac_vf;
+        emit_vfnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmsac_vv;
+        emit_vfnmsub_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmsub_vf;
+        emit_vfnmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfnmsub_vv;
+        emit_vfrdiv_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfrdiv_vf;
+        emit_vfrec7_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfrec7_v;
+        emit_vfredmax_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfredmax_vs;
+        emit_vfredmin_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfredmin_vs;
+        emit_vfredosum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfredosum_vs;
+        emit_vfredusum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfredusum_vs;
+        emit_vfrsqrt7_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfrsqrt7_v;
+        emit_vfrsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfrsub_vf;
+        emit_vfsgnj_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfsgnj_vf;
+        emit_vfsgnj_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfsgnj_vv;
+        emit_vfsgnjn_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfsgnjn_vf;
+        emit_vfsgnjn_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfsgnjn_vv;
+        emit_vfsgnjx_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfsgnjx_vf;
+        emit_vfsgnjx_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfsgnjx_vv;
+        emit_vfslide1down_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfslide1down_vf;
+        emit_vfslide1up_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfslide1up_vf;
+        emit_vfsqrt_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfsqrt_v;
+        emit_vfsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfsub_vf;
+        emit_vfsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfsub_vv;
+        emit_vfwadd_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfwadd_vf;
+        emit_vfwadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfwadd_vv;
+        emit_vfwadd_wf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfwadd_wf;
+        emit_vfwadd_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfwadd_wv;
+        emit_vfwcvt_f_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwcvt_f_f_v;
+        emit_vfwcvt_f_x_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwcvt_f_x_v;
+        emit_vfwcvt_f_xu_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwcvt_f_xu_v;
+        emit_vfwcvt_rtz_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwcvt_rtz_x_f_v;
+        emit_vfwcvt_rtz_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwcvt_rtz_xu_f_v;
+        emit_vfwcvt_x_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwcvt_x_f_v;
+        emit_vfwcvt_xu_f_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwcvt_xu_f_v;
+        emit_vfwmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfwmacc_vf;
+        emit_vfwmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwmacc_vv;
+        emit_vfwmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfwmsac_vf;
+        emit_vfwmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwmsac_vv;
+        emit_vfwmul_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfwmul_vf;
+        emit_vfwmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfwmul_vv;
+        emit_vfwnmacc_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfwnmacc_vf;
+        emit_vfwnmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwnmacc_vv;
+        emit_vfwnmsac_vf(vd: VRegister, rs1: FRegister, vs2: VRegister, vm: VMask) => rv32v::vfwnmsac_vf;
+        emit_vfwnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vfwnmsac_vv;
+        emit_vfwredosum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfwredosum_vs;
+        emit_vfwredusum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfwredusum_vs;
+        emit_vfwsub_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfwsub_vf;
+        emit_vfwsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfwsub_vv;
+        emit_vfwsub_wf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vfwsub_wf;
+        emit_vfwsub_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vfwsub_wv;
+        emit_vid_v(vd: VRegister, vm: VMask) => rv32v::vid_v;
+        emit_viota_m(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::viota_m;
+        emit_vle_v(eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => rv32v::vle_v;
+        emit_vleff_v(eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => rv32v::vleff_v;
+        emit_vlm_v(vd: VRegister, base: Register) => rv32v::vlm_v;
+        emit_vloxei_v(eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vloxei_v;
+        emit_vloxseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vloxseg_v;
+        emit_vlre_v(nf: u8, eew: Sew, vd: VRegister, base: Register) => rv32v::vlre_v;
+        emit_vlse_v(eew: Sew, vd: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => rv32v::vlse_v;
+        emit_vlseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => rv32v::vlseg_v;
+        emit_vlsegff_v(nf: u8, eew: Sew, vd: VRegister, base: Register, vm: VMask, vtype: VType) => rv32v::vlsegff_v;
+        emit_vlsseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => rv32v::vlsseg_v;
+        emit_vluxei_v(eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vluxei_v;
+        emit_vluxseg_v(nf: u8, eew: Sew, vd: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vluxseg_v;
+        emit_vmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vmacc_vv;
+        emit_vmacc_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vmacc_vx;
+        emit_vmadc_vi(vd: VRegister, vs2: VRegister, imm: i8) => rv32v::vmadc_vi;
+        emit_vmadc_vim(vd: VRegister, vs2: VRegister, imm: i8) => rv32v::vmadc_vim;
+        emit_vmadc_vv(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmadc_vv;
+        emit_vmadc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmadc_vvm;
+        emit_vmadc_vx(vd: VRegister, vs2: VRegister, rs1: Register) => rv32v::vmadc_vx;
+        emit_vmadc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => rv32v::vmadc_vxm;
+        emit_vmadd_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vmadd_vv;
+        emit_vmadd_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vmadd_vx;
+        emit_vmand_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmand_mm;
+        emit_vmandn_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmandn_mm;
+        emit_vmax_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmax_vv;
+        emit_vmax_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmax_vx;
+        emit_vmaxu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmaxu_vv;
+        emit_vmaxu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmaxu_vx;
+        emit_vmclr_m(vd: VRegister) => rv32v::vmclr_m;
+        emit_vmerge_vim(vd: VRegister, vs2: VRegister, imm: i8) => rv32v::vmerge_vim;
+        emit_vmerge_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmerge_vvm;
+        emit_vmerge_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => rv32v::vmerge_vxm;
+        emit_vmfeq_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vmfeq_vf;
+        emit_vmfeq_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmfeq_vv;
+        emit_vmfge_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vmfge_vf;
+        emit_vmfgt_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vmfgt_vf;
+        emit_vmfle_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vmfle_vf;
+        emit_vmfle_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmfle_vv;
+        emit_vmflt_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vmflt_vf;
+        emit_vmflt_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmflt_vv;
+        emit_vmfne_vf(vd: VRegister, vs2: VRegister, rs1: FRegister, vm: VMask) => rv32v::vmfne_vf;
+        emit_vmfne_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmfne_vv;
+        emit_vmin_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmin_vv;
+        emit_vmin_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmin_vx;
+        emit_vminu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vminu_vv;
+        emit_vminu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vminu_vx;
+        emit_vmmv_m(vd: VRegister, vs: VRegister) => rv32v::vmmv_m;
+        emit_vmnand_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmnand_mm;
+        emit_vmnor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmnor_mm;
+        emit_vmnot_m(vd: VRegister, vs: VRegister) => rv32v::vmnot_m;
+        emit_vmor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmor_mm;
+        emit_vmorn_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmorn_mm;
+        emit_vmsbc_vv(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmsbc_vv;
+        emit_vmsbc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmsbc_vvm;
+        emit_vmsbc_vx(vd: VRegister, vs2: VRegister, rs1: Register) => rv32v::vmsbc_vx;
+        emit_vmsbc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => rv32v::vmsbc_vxm;
+        emit_vmsbf_m(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vmsbf_m;
+        emit_vmseq_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vmseq_vi;
+        emit_vmseq_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmseq_vv;
+        emit_vmseq_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmseq_vx;
+        emit_vmset_m(vd: VRegister) => rv32v::vmset_m;
+        emit_vmsgt_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vmsgt_vi;
+        emit_vmsgt_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmsgt_vx;
+        emit_vmsgtu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vmsgtu_vi;
+        emit_vmsgtu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmsgtu_vx;
+        emit_vmsif_m(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vmsif_m;
+        emit_vmsle_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vmsle_vi;
+        emit_vmsle_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmsle_vv;
+        emit_vmsle_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmsle_vx;
+        emit_vmsleu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vmsleu_vi;
+        emit_vmsleu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmsleu_vv;
+        emit_vmsleu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmsleu_vx;
+        emit_vmslt_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmslt_vv;
+        emit_vmslt_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmslt_vx;
+        emit_vmsltu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmsltu_vv;
+        emit_vmsltu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmsltu_vx;
+        emit_vmsne_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vmsne_vi;
+        emit_vmsne_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmsne_vv;
+        emit_vmsne_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmsne_vx;
+        emit_vmsof_m(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vmsof_m;
+        emit_vmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmul_vv;
+        emit_vmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmul_vx;
+        emit_vmulh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmulh_vv;
+        emit_vmulh_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmulh_vx;
+        emit_vmulhsu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmulhsu_vv;
+        emit_vmulhsu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmulhsu_vx;
+        emit_vmulhu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vmulhu_vv;
+        emit_vmulhu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vmulhu_vx;
+        emit_vmv_s_x(vd: VRegister, rs1: Register) => rv32v::vmv_s_x;
+        emit_vmv_v_i(vd: VRegister, imm: i8) => rv32v::vmv_v_i;
+        emit_vmv_v_v(vd: VRegister, vs1: VRegister) => rv32v::vmv_v_v;
+        emit_vmv_v_x(vd: VRegister, rs1: Register) => rv32v::vmv_v_x;
+        emit_vmv_x_s(rd: Register, vs2: VRegister) => rv32v::vmv_x_s;
+        emit_vmvr_v(nr: u8, vd: VRegister, vs2: VRegister) => rv32v::vmvr_v;
+        emit_vmxnor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmxnor_mm;
+        emit_vmxor_mm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vmxor_mm;
+        emit_vnclip_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vnclip_wi;
+        emit_vnclip_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vnclip_wv;
+        emit_vnclip_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vnclip_wx;
+        emit_vnclipu_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vnclipu_wi;
+        emit_vnclipu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vnclipu_wv;
+        emit_vnclipu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vnclipu_wx;
+        emit_vneg_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vneg_v;
+        emit_vnmsac_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vnmsac_vv;
+        emit_vnmsac_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vnmsac_vx;
+        emit_vnmsub_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vnmsub_vv;
+        emit_vnmsub_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vnmsub_vx;
+        emit_vnot_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vnot_v;
+        emit_vnsra_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vnsra_wi;
+        emit_vnsra_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vnsra_wv;
+        emit_vnsra_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vnsra_wx;
+        emit_vnsrl_wi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vnsrl_wi;
+        emit_vnsrl_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vnsrl_wv;
+        emit_vnsrl_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vnsrl_wx;
+        emit_vor_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vor_vi;
+        emit_vor_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vor_vv;
+        emit_vor_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vor_vx;
+        emit_vredand_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredand_vs;
+        emit_vredmax_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredmax_vs;
+        emit_vredmaxu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredmaxu_vs;
+        emit_vredmin_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredmin_vs;
+        emit_vredminu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredminu_vs;
+        emit_vredor_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredor_vs;
+        emit_vredsum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredsum_vs;
+        emit_vredxor_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vredxor_vs;
+        emit_vrem_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vrem_vv;
+        emit_vrem_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vrem_vx;
+        emit_vremu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vremu_vv;
+        emit_vremu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vremu_vx;
+        emit_vrgather_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vrgather_vi;
+        emit_vrgather_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vrgather_vv;
+        emit_vrgather_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vrgather_vx;
+        emit_vrgatherei16_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vrgatherei16_vv;
+        emit_vrsub_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vrsub_vi;
+        emit_vrsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vrsub_vx;
+        emit_vsadd_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vsadd_vi;
+        emit_vsadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vsadd_vv;
+        emit_vsadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vsadd_vx;
+        emit_vsaddu_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vsaddu_vi;
+        emit_vsaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vsaddu_vv;
+        emit_vsaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vsaddu_vx;
+        emit_vsbc_vvm(vd: VRegister, vs2: VRegister, vs1: VRegister) => rv32v::vsbc_vvm;
+        emit_vsbc_vxm(vd: VRegister, vs2: VRegister, rs1: Register) => rv32v::vsbc_vxm;
+        emit_vse_v(eew: Sew, vs: VRegister, base: Register, vm: VMask, vtype: VType) => rv32v::vse_v;
+        emit_vsetivli(rd: Register, avl: u8, vtype: VType) => rv32v::vsetivli;
+        emit_vsetvl(rd: Register, rs1: Register, rs2: Register) => rv32v::vsetvl;
+        emit_vsetvli(rd: Register, rs: Register, vtype: VType) => rv32v::vsetvli;
+        emit_vsext_vf2(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vsext_vf2;
+        emit_vsext_vf4(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vsext_vf4;
+        emit_vsext_vf8(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vsext_vf8;
+        emit_vslide1down_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vslide1down_vx;
+        emit_vslide1up_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vslide1up_vx;
+        emit_vslidedown_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vslidedown_vi;
+        emit_vslidedown_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vslidedown_vx;
+        emit_vslideup_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vslideup_vi;
+        emit_vslideup_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vslideup_vx;
+        emit_vsll_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vsll_vi;
+        emit_vsll_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vsll_vv;
+        emit_vsll_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vsll_vx;
+        emit_vsm_v(vs: VRegister, base: Register) => rv32v::vsm_v;
+        emit_vsmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vsmul_vv;
+        emit_vsmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vsmul_vx;
+        emit_vsoxei_v(eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vsoxei_v;
+        emit_vsoxseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vsoxseg_v;
+        emit_vsr_v(nf: u8, vs: VRegister, base: Register) => rv32v::vsr_v;
+        emit_vsra_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vsra_vi;
+        emit_vsra_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vsra_vv;
+        emit_vsra_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vsra_vx;
+        emit_vsrl_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vsrl_vi;
+        emit_vsrl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vsrl_vv;
+        emit_vsrl_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vsrl_vx;
+        emit_vsse_v(eew: Sew, vs: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => rv32v::vsse_v;
+        emit_vsseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, vm: VMask, vtype: VType) => rv32v::vsseg_v;
+        emit_vssra_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vssra_vi;
+        emit_vssra_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vssra_vv;
+        emit_vssra_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vssra_vx;
+        emit_vssrl_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32v::vssrl_vi;
+        emit_vssrl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vssrl_vv;
+        emit_vssrl_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vssrl_vx;
+        emit_vssseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, stride: Register, vm: VMask, vtype: VType) => rv32v::vssseg_v;
+        emit_vssub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vssub_vv;
+        emit_vssub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vssub_vx;
+        emit_vssubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vssubu_vv;
+        emit_vssubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vssubu_vx;
+        emit_vsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vsub_vv;
+        emit_vsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vsub_vx;
+        emit_vsuxei_v(eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vsuxei_v;
+        emit_vsuxseg_v(nf: u8, eew: Sew, vs: VRegister, base: Register, index: VRegister, vm: VMask, vtype: VType) => rv32v::vsuxseg_v;
+        emit_vwadd_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwadd_vv;
+        emit_vwadd_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwadd_vx;
+        emit_vwadd_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwadd_wv;
+        emit_vwadd_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwadd_wx;
+        emit_vwaddu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwaddu_vv;
+        emit_vwaddu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwaddu_vx;
+        emit_vwaddu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwaddu_wv;
+        emit_vwaddu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwaddu_wx;
+        emit_vwmacc_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vwmacc_vv;
+        emit_vwmacc_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vwmacc_vx;
+        emit_vwmaccsu_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vwmaccsu_vv;
+        emit_vwmaccsu_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vwmaccsu_vx;
+        emit_vwmaccu_vv(vd: VRegister, vs1: VRegister, vs2: VRegister, vm: VMask) => rv32v::vwmaccu_vv;
+        emit_vwmaccu_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vwmaccu_vx;
+        emit_vwmaccus_vx(vd: VRegister, rs1: Register, vs2: VRegister, vm: VMask) => rv32v::vwmaccus_vx;
+        emit_vwmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwmul_vv;
+        emit_vwmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwmul_vx;
+        emit_vwmulsu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwmulsu_vv;
+        emit_vwmulsu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwmulsu_vx;
+        emit_vwmulu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwmulu_vv;
+        emit_vwmulu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwmulu_vx;
+        emit_vwredsum_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwredsum_vs;
+        emit_vwredsumu_vs(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwredsumu_vs;
+        emit_vwsub_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwsub_vv;
+        emit_vwsub_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwsub_vx;
+        emit_vwsub_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwsub_wv;
+        emit_vwsub_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwsub_wx;
+        emit_vwsubu_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwsubu_vv;
+        emit_vwsubu_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwsubu_vx;
+        emit_vwsubu_wv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vwsubu_wv;
+        emit_vwsubu_wx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vwsubu_wx;
+        emit_vxor_vi(vd: VRegister, vs2: VRegister, imm: i8, vm: VMask) => rv32v::vxor_vi;
+        emit_vxor_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32v::vxor_vv;
+        emit_vxor_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32v::vxor_vx;
+        emit_vzext_vf2(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vzext_vf2;
+        emit_vzext_vf4(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vzext_vf4;
+        emit_vzext_vf8(vd: VRegister, vs2: VRegister, vm: VMask) => rv32v::vzext_vf8;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
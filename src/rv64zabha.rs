@@ -0,0 +1,35 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zabha;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_amoadd_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoadd_b;
+        emit_amoadd_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoadd_h;
+        emit_amoand_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoand_b;
+        emit_amoand_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoand_h;
+        emit_amocas_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amocas_b;
+        emit_amocas_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amocas_h;
+        emit_amomax_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amomax_b;
+        emit_amomax_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amomax_h;
+        emit_amomaxu_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amomaxu_b;
+        emit_amomaxu_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amomaxu_h;
+        emit_amomin_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amomin_b;
+        emit_amomin_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amomin_h;
+        emit_amominu_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amominu_b;
+        emit_amominu_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amominu_h;
+        emit_amoor_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoor_b;
+        emit_amoor_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoor_h;
+        emit_amoswap_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoswap_b;
+        emit_amoswap_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoswap_h;
+        emit_amoxor_b(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoxor_b;
+        emit_amoxor_h(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zabha::amoxor_h;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
@@ -0,0 +1,66 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zacas;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_amocas_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amocas_d;
+        emit_amocas_q(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => amocas_q;
+    }
+
+    forward! {
+        emit_amocas_w(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) => rv32zacas::amocas_w;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn amocas_d(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    AType { funct3: 0b011, funct5: 0b00101, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+#[inline]
+pub fn amocas_q(rd: Register, rs: Register, base: Register, ordering: AmoOrdering) -> u32 {
+    assert!(is_register_pair(rd));
+    assert!(is_register_pair(rs));
+    AType { funct3: 0b100, funct5: 0b00101, ordering, rd, rs1: base, rs2: rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::AmoOrdering::*;
+
+    #[test]
+    fn test_amocas_d() {
+        assert_eq!(0x2cd7b5af, amocas_d(A1, A3, A5, Acquire));
+    }
+
+    #[test]
+    fn test_amocas_q() {
+        assert_eq!(0x2cc7c52f, amocas_q(A0, A2, A5, Acquire));
+        assert_eq!(0x2fc1442f, amocas_q(S0, T3, Sp, AcqRel));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_amocas_q_odd_rd() {
+        amocas_q(A1, A2, A5, Relaxed);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_amocas_q_odd_rs() {
+        amocas_q(A0, A3, A5, Relaxed);
+    }
+}
@@ -0,0 +1,74 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zbb;
+use crate::rv32zbkb;
+use crate::rv64zbb;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_packw(rd: Register, rs1: Register, rs2: Register) => packw;
+    }
+
+    forward! {
+        emit_brev8(rd: Register, rs: Register) => rv32zbkb::brev8;
+        emit_pack(rd: Register, rs1: Register, rs2: Register) => rv32zbkb::pack;
+        emit_packh(rd: Register, rs1: Register, rs2: Register) => rv32zbkb::packh;
+    }
+
+    forward! {
+        emit_andn(rd: Register, rs1: Register, rs2: Register) => rv32zbb::andn;
+        emit_orn(rd: Register, rs1: Register, rs2: Register) => rv32zbb::orn;
+        emit_rev8(rd: Register, rs: Register) => rv64zbb::rev8;
+        emit_rol(rd: Register, rs1: Register, rs2: Register) => rv32zbb::rol;
+        emit_rolw(rd: Register, rs1: Register, rs2: Register) => rv64zbb::rolw;
+        emit_ror(rd: Register, rs1: Register, rs2: Register) => rv32zbb::ror;
+        emit_rori(rd: Register, rs: Register, shamt: u8) => rv64zbb::rori;
+        emit_roriw(rd: Register, rs: Register, shamt: u8) => rv64zbb::roriw;
+        emit_rorw(rd: Register, rs1: Register, rs2: Register) => rv64zbb::rorw;
+        emit_xnor(rd: Register, rs1: Register, rs2: Register) => rv32zbb::xnor;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn packw(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op32, funct3: 0b100, funct7: 0b0000100, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    struct Buffer(Vec<u8>);
+
+    impl EmitSlice for Buffer {
+        type Error = ();
+
+        fn emit_slice(&mut self, s: &[u8]) -> Result<(), ()> {
+            self.0.extend_from_slice(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_packw() {
+        assert_eq!(0x08c5c53b, packw(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_rev8() {
+        let mut buffer = Buffer(Vec::new());
+        Emit::emit_rev8(&mut buffer, A0, A1).unwrap();
+        assert_eq!(0x6b85d513_u32.to_le_bytes().to_vec(), buffer.0);
+    }
+}
@@ -0,0 +1,17 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zbkx;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_xperm4(rd: Register, rs1: Register, rs2: Register) => rv32zbkx::xperm4;
+        emit_xperm8(rd: Register, rs1: Register, rs2: Register) => rv32zbkx::xperm8;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
@@ -0,0 +1,49 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zcb;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_c_zext_w(rd: CRegister) => zext_w;
+    }
+
+    forward! {
+        emit_c_lbu(rd: CRegister, base: CRegister, offset: u8) => rv32zcb::lbu;
+        emit_c_lh(rd: CRegister, base: CRegister, offset: u8) => rv32zcb::lh;
+        emit_c_lhu(rd: CRegister, base: CRegister, offset: u8) => rv32zcb::lhu;
+        emit_c_mul(rd: CRegister, rs: CRegister) => rv32zcb::mul;
+        emit_c_not(rd: CRegister) => rv32zcb::not;
+        emit_c_sb(rs: CRegister, base: CRegister, offset: u8) => rv32zcb::sb;
+        emit_c_sext_b(rd: CRegister) => rv32zcb::sext_b;
+        emit_c_sext_h(rd: CRegister) => rv32zcb::sext_h;
+        emit_c_sh(rs: CRegister, base: CRegister, offset: u8) => rv32zcb::sh;
+        emit_c_zext_b(rd: CRegister) => rv32zcb::zext_b;
+        emit_c_zext_h(rd: CRegister) => rv32zcb::zext_h;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn zext_w(rd: CRegister) -> u16 {
+    encode!(i6(0b100111), i3(rd as u32), i2(0b11), i3(0b100), i2(0b01)) as u16
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CRegister::*;
+
+    #[test]
+    fn test_zext_w() {
+        assert_eq!(0x9d71, zext_w(A0));
+    }
+}
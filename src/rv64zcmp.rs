@@ -0,0 +1,110 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zcmp;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_cm_pop(rlist: RegisterList, stack_adj: u16) => pop;
+        emit_cm_popret(rlist: RegisterList, stack_adj: u16) => popret;
+        emit_cm_popretz(rlist: RegisterList, stack_adj: u16) => popretz;
+        emit_cm_push(rlist: RegisterList, stack_adj: u16) => push;
+    }
+
+    forward! {
+        emit_cm_mva01s(rs1: SRegister, rs2: SRegister) => rv32zcmp::mva01s;
+        emit_cm_mvsa01(rs1: SRegister, rs2: SRegister) => rv32zcmp::mvsa01;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn push(rlist: RegisterList, stack_adj: u16) -> u16 {
+    CmPushPopType { funct5: 0b11000, rlist, spimm: zcmp_spimm(rlist, stack_adj, 8) }.encode()
+}
+
+#[inline]
+pub fn pop(rlist: RegisterList, stack_adj: u16) -> u16 {
+    CmPushPopType { funct5: 0b11010, rlist, spimm: zcmp_spimm(rlist, stack_adj, 8) }.encode()
+}
+
+#[inline]
+pub fn popretz(rlist: RegisterList, stack_adj: u16) -> u16 {
+    CmPushPopType { funct5: 0b11100, rlist, spimm: zcmp_spimm(rlist, stack_adj, 8) }.encode()
+}
+
+#[inline]
+pub fn popret(rlist: RegisterList, stack_adj: u16) -> u16 {
+    CmPushPopType { funct5: 0b11110, rlist, spimm: zcmp_spimm(rlist, stack_adj, 8) }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pop() {
+        assert_eq!(0xba42, pop(RegisterList::Ra, 16));
+        assert_eq!(0xba4e, pop(RegisterList::Ra, 64));
+        assert_eq!(0xba82, pop(RegisterList::RaS0S3, 48));
+        assert_eq!(0xba8e, pop(RegisterList::RaS0S3, 96));
+        assert_eq!(0xbaf2, pop(RegisterList::RaS0S11, 112));
+        assert_eq!(0xbafe, pop(RegisterList::RaS0S11, 160));
+    }
+
+    #[test]
+    fn test_popret() {
+        assert_eq!(0xbe42, popret(RegisterList::Ra, 16));
+        assert_eq!(0xbe4e, popret(RegisterList::Ra, 64));
+        assert_eq!(0xbe82, popret(RegisterList::RaS0S3, 48));
+        assert_eq!(0xbe8e, popret(RegisterList::RaS0S3, 96));
+        assert_eq!(0xbef2, popret(RegisterList::RaS0S11, 112));
+        assert_eq!(0xbefe, popret(RegisterList::RaS0S11, 160));
+    }
+
+    #[test]
+    fn test_popretz() {
+        assert_eq!(0xbc42, popretz(RegisterList::Ra, 16));
+        assert_eq!(0xbc4e, popretz(RegisterList::Ra, 64));
+        assert_eq!(0xbc82, popretz(RegisterList::RaS0S3, 48));
+        assert_eq!(0xbc8e, popretz(RegisterList::RaS0S3, 96));
+        assert_eq!(0xbcf2, popretz(RegisterList::RaS0S11, 112));
+        assert_eq!(0xbcfe, popretz(RegisterList::RaS0S11, 160));
+    }
+
+    #[test]
+    fn test_push() {
+        assert_eq!(0xb842, push(RegisterList::Ra, 16));
+        assert_eq!(0xb84e, push(RegisterList::Ra, 64));
+        assert_eq!(0xb882, push(RegisterList::RaS0S3, 48));
+        assert_eq!(0xb88e, push(RegisterList::RaS0S3, 96));
+        assert_eq!(0xb8f2, push(RegisterList::RaS0S11, 112));
+        assert_eq!(0xb8fe, push(RegisterList::RaS0S11, 160));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_above_max() {
+        push(RegisterList::RaS0S11, 176);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_below_base() {
+        push(RegisterList::RaS0S11, 96);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_unaligned() {
+        push(RegisterList::RaS0S11, 120);
+    }
+}
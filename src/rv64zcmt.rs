@@ -0,0 +1,60 @@
+use mitte_core::EmitSlice;
+
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32zcmt;
+
+
+pub trait Emit: EmitSlice {
+    /// Emits a jump table for `cm.jt` and `cm.jalt` with one entry per label in `targets`.
+    ///
+    /// `address` is the absolute address the table will be loaded at and must be 64-byte aligned.
+    /// Returns the value to write to the `jvt` CSR.
+    fn emit_jump_table<Label>(&mut self, address: u64, targets: &mut [Label]) -> Result<u64, Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        for (entry, label) in table_entries(address, targets.len()).zip(targets.iter_mut()) {
+            self.emit_branch(
+                label,
+                FixupKind::Address64(entry),
+                |e, offset| {
+                    e.emit_slice(&FixupKind::Address64(entry).apply_fixup_address(offset).to_le_bytes())
+                },
+            )?;
+        }
+        Ok(address)
+    }
+
+    forward! {
+        emit_cm_jalt(index: u8) => rv32zcmt::jalt;
+        emit_cm_jt(index: u8) => rv32zcmt::jt;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+fn table_entries(address: u64, count: usize) -> impl Iterator<Item = u64> {
+    assert!(address & 63 == 0 && count <= 256);
+    (0..count as u64).map(move |index| address + index * 8)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_entries() {
+        assert_eq!(vec![0x1_0000_0000, 0x1_0000_0008, 0x1_0000_0010], table_entries(0x1_0000_0000, 3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_table_entries_unaligned() {
+        let _ = table_entries(0x1_0000_0020, 1);
+    }
+}
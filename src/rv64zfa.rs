@@ -0,0 +1,79 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zfa;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_fmvh_x_q(rd: Register, rs: FRegister) => fmvh_x_q;
+        emit_fmvp_q_x(rd: FRegister, rs1: Register, rs2: Register) => fmvp_q_x;
+    }
+
+    forward! {
+        emit_fcvtmod_w_d(rd: Register, rs: FRegister) => rv32zfa::fcvtmod_w_d;
+        emit_fleq_d(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fleq_d;
+        emit_fleq_h(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fleq_h;
+        emit_fleq_q(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fleq_q;
+        emit_fleq_s(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fleq_s;
+        emit_fli_d(rd: FRegister, imm: FliConstant) => rv32zfa::fli_d;
+        emit_fli_h(rd: FRegister, imm: FliConstant) => rv32zfa::fli_h;
+        emit_fli_q(rd: FRegister, imm: FliConstant) => rv32zfa::fli_q;
+        emit_fli_s(rd: FRegister, imm: FliConstant) => rv32zfa::fli_s;
+        emit_fltq_d(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fltq_d;
+        emit_fltq_h(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fltq_h;
+        emit_fltq_q(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fltq_q;
+        emit_fltq_s(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfa::fltq_s;
+        emit_fmaxm_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fmaxm_d;
+        emit_fmaxm_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fmaxm_h;
+        emit_fmaxm_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fmaxm_q;
+        emit_fmaxm_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fmaxm_s;
+        emit_fminm_d(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fminm_d;
+        emit_fminm_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fminm_h;
+        emit_fminm_q(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fminm_q;
+        emit_fminm_s(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfa::fminm_s;
+        emit_fround_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::fround_d;
+        emit_fround_h(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::fround_h;
+        emit_fround_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::fround_q;
+        emit_fround_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::fround_s;
+        emit_froundnx_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::froundnx_d;
+        emit_froundnx_h(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::froundnx_h;
+        emit_froundnx_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::froundnx_q;
+        emit_froundnx_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfa::froundnx_s;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn fmvh_x_q(rd: Register, rs: FRegister) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1110011, rd, rs1: rs, rs2: 0b00001_u8 }.encode()
+}
+
+#[inline]
+pub fn fmvp_q_x(rd: FRegister, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: OpFp, funct3: 0b000, funct7: 0b1011011, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+
+    #[test]
+    fn test_fmvh_x_q() {
+        assert_eq!(0xe6158553, fmvh_x_q(A0, Fa1));
+    }
+
+    #[test]
+    fn test_fmvp_q_x() {
+        assert_eq!(0xb6b50553, fmvp_q_x(Fa0, A0, A1));
+    }
+}
@@ -0,0 +1,121 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32zfh;
+use crate::rv32zfhmin;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flh_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        rv32zfhmin::Emit::emit_flh_label(self, rd, rt, label)
+    }
+
+    forward! {
+        emit_fcvt_h_l(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_h_l;
+        emit_fcvt_h_lu(rd: FRegister, rs: Register, rm: RoundingMode) => fcvt_h_lu;
+        emit_fcvt_l_h(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_l_h;
+        emit_fcvt_lu_h(rd: Register, rs: FRegister, rm: RoundingMode) => fcvt_lu_h;
+    }
+
+    forward! {
+        emit_fabs_h(rd: FRegister, rs: FRegister) => rv32zfh::fabs_h;
+        emit_fadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32zfh::fadd_h;
+        emit_fclass_h(rd: Register, rs: FRegister) => rv32zfh::fclass_h;
+        emit_fcvt_h_w(rd: FRegister, rs: Register, rm: RoundingMode) => rv32zfh::fcvt_h_w;
+        emit_fcvt_h_wu(rd: FRegister, rs: Register, rm: RoundingMode) => rv32zfh::fcvt_h_wu;
+        emit_fcvt_w_h(rd: Register, rs: FRegister, rm: RoundingMode) => rv32zfh::fcvt_w_h;
+        emit_fcvt_wu_h(rd: Register, rs: FRegister, rm: RoundingMode) => rv32zfh::fcvt_wu_h;
+        emit_fdiv_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32zfh::fdiv_h;
+        emit_feq_h(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfh::feq_h;
+        emit_fle_h(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfh::fle_h;
+        emit_flt_h(rd: Register, rs1: FRegister, rs2: FRegister) => rv32zfh::flt_h;
+        emit_fmadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32zfh::fmadd_h;
+        emit_fmax_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfh::fmax_h;
+        emit_fmin_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfh::fmin_h;
+        emit_fmsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32zfh::fmsub_h;
+        emit_fmul_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32zfh::fmul_h;
+        emit_fmv_h(rd: FRegister, rs: FRegister) => rv32zfh::fmv_h;
+        emit_fneg_h(rd: FRegister, rs: FRegister) => rv32zfh::fneg_h;
+        emit_fnmadd_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32zfh::fnmadd_h;
+        emit_fnmsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rs3: FRegister, rm: RoundingMode) => rv32zfh::fnmsub_h;
+        emit_fsgnj_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfh::fsgnj_h;
+        emit_fsgnjn_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfh::fsgnjn_h;
+        emit_fsgnjx_h(rd: FRegister, rs1: FRegister, rs2: FRegister) => rv32zfh::fsgnjx_h;
+        emit_fsqrt_h(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfh::fsqrt_h;
+        emit_fsub_h(rd: FRegister, rs1: FRegister, rs2: FRegister, rm: RoundingMode) => rv32zfh::fsub_h;
+    }
+
+    forward! {
+        emit_fcvt_d_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_d_h;
+        emit_fcvt_h_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_d;
+        emit_fcvt_h_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_q;
+        emit_fcvt_h_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_s;
+        emit_fcvt_q_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_q_h;
+        emit_fcvt_s_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_s_h;
+        emit_flh(rd: FRegister, base: Register, offset: i16) => rv32zfhmin::flh;
+        emit_fmv_h_x(rd: FRegister, rs: Register) => rv32zfhmin::fmv_h_x;
+        emit_fmv_x_h(rd: Register, rs: FRegister) => rv32zfhmin::fmv_x_h;
+        emit_fsh(rs: FRegister, base: Register, offset: i16) => rv32zfhmin::fsh;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn fcvt_l_h(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100010, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_lu_h(rd: Register, rs: FRegister, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1100010, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_h_l(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101010, rd, rs1: rs, rs2: 0b00010_u8 }.encode()
+}
+
+#[inline]
+pub fn fcvt_h_lu(rd: FRegister, rs: Register, rm: RoundingMode) -> u32 {
+    RType { opcode: OpFp, funct3: rm as u8, funct7: 0b1101010, rd, rs1: rs, rs2: 0b00011_u8 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+    use crate::types::FRegister::*;
+    use crate::types::RoundingMode::*;
+
+    #[test]
+    fn test_fcvt_h_l() {
+        assert_eq!(0xd4250553, fcvt_h_l(Fa0, A0, Rne));
+    }
+
+    #[test]
+    fn test_fcvt_h_lu() {
+        assert_eq!(0xd43fb053, fcvt_h_lu(Ft0, T6, Rup));
+    }
+
+    #[test]
+    fn test_fcvt_l_h() {
+        assert_eq!(0xc4251553, fcvt_l_h(A0, Fa0, Rtz));
+    }
+
+    #[test]
+    fn test_fcvt_lu_h() {
+        assert_eq!(0xc43072d3, fcvt_lu_h(T0, Ft0, Dyn));
+    }
+}
@@ -0,0 +1,34 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+use crate::fixup::FixupKind;
+
+use crate::macros::forward;
+
+use crate::rv32zfhmin;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_flh_label<Label>(&mut self, rd: FRegister, rt: Register, label: &mut Label)
+        -> Result<(), Self::Error>
+    where Self: mitte_core::Emit,
+          Label: mitte_core::Label<Self, FixupKind>
+    {
+        rv32zfhmin::Emit::emit_flh_label(self, rd, rt, label)
+    }
+
+    forward! {
+        emit_fcvt_d_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_d_h;
+        emit_fcvt_h_d(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_d;
+        emit_fcvt_h_q(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_q;
+        emit_fcvt_h_s(rd: FRegister, rs: FRegister, rm: RoundingMode) => rv32zfhmin::fcvt_h_s;
+        emit_fcvt_q_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_q_h;
+        emit_fcvt_s_h(rd: FRegister, rs: FRegister) => rv32zfhmin::fcvt_s_h;
+        emit_flh(rd: FRegister, base: Register, offset: i16) => rv32zfhmin::flh;
+        emit_fmv_h_x(rd: FRegister, rs: Register) => rv32zfhmin::fmv_h_x;
+        emit_fmv_x_h(rd: Register, rs: FRegister) => rv32zfhmin::fmv_x_h;
+        emit_fsh(rs: FRegister, base: Register, offset: i16) => rv32zfhmin::fsh;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
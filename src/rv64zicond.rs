@@ -0,0 +1,23 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zicond;
+
+
+pub trait Emit: EmitSlice {
+    fn emit_select(&mut self, rd: Register, cond: Register, a: Register, b: Register, tmp: Register)
+        -> Result<(), Self::Error>
+    {
+        rv32zicond::Emit::emit_select(self, rd, cond, a, b, tmp)
+    }
+
+    forward! {
+        emit_czero_eqz(rd: Register, rs1: Register, rs2: Register) => rv32zicond::czero_eqz;
+        emit_czero_nez(rd: Register, rs1: Register, rs2: Register) => rv32zicond::czero_nez;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
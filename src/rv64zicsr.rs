@@ -0,0 +1,28 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zicsr;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_csrc(csr: Csr, rs: Register) => rv32zicsr::csrc;
+        emit_csrci(csr: Csr, imm: u8) => rv32zicsr::csrci;
+        emit_csrr(rd: Register, csr: Csr) => rv32zicsr::csrr;
+        emit_csrrc(rd: Register, csr: Csr, rs: Register) => rv32zicsr::csrrc;
+        emit_csrrci(rd: Register, csr: Csr, imm: u8) => rv32zicsr::csrrci;
+        emit_csrrs(rd: Register, csr: Csr, rs: Register) => rv32zicsr::csrrs;
+        emit_csrrsi(rd: Register, csr: Csr, imm: u8) => rv32zicsr::csrrsi;
+        emit_csrrw(rd: Register, csr: Csr, rs: Register) => rv32zicsr::csrrw;
+        emit_csrrwi(rd: Register, csr: Csr, imm: u8) => rv32zicsr::csrrwi;
+        emit_csrs(csr: Csr, rs: Register) => rv32zicsr::csrs;
+        emit_csrsi(csr: Csr, imm: u8) => rv32zicsr::csrsi;
+        emit_csrw(csr: Csr, rs: Register) => rv32zicsr::csrw;
+        emit_csrwi(csr: Csr, imm: u8) => rv32zicsr::csrwi;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
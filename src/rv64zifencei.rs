@@ -0,0 +1,14 @@
+use mitte_core::EmitSlice;
+
+use crate::macros::forward;
+
+use crate::rv32zifencei;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_fence_i() => rv32zifencei::fence_i;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
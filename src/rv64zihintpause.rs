@@ -0,0 +1,14 @@
+use mitte_core::EmitSlice;
+
+use crate::macros::forward;
+
+use crate::rv32zihintpause;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_pause() => rv32zihintpause::pause;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
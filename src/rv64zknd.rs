@@ -0,0 +1,62 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv64zkne;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_aes64ds(rd: Register, rs1: Register, rs2: Register) => aes64ds;
+        emit_aes64dsm(rd: Register, rs1: Register, rs2: Register) => aes64dsm;
+        emit_aes64im(rd: Register, rs: Register) => aes64im;
+    }
+
+    forward! {
+        emit_aes64ks1i(rd: Register, rs: Register, rnum: u8) => rv64zkne::aes64ks1i;
+        emit_aes64ks2(rd: Register, rs1: Register, rs2: Register) => rv64zkne::aes64ks2;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn aes64ds(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0011101, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn aes64dsm(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0011111, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn aes64im(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0011000_00000, rd, rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_aes64ds() {
+        assert_eq!(0x3ac58533, aes64ds(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_aes64dsm() {
+        assert_eq!(0x3ec58533, aes64dsm(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_aes64im() {
+        assert_eq!(0x30059513, aes64im(A0, A1));
+    }
+}
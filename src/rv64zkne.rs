@@ -0,0 +1,74 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_aes64es(rd: Register, rs1: Register, rs2: Register) => aes64es;
+        emit_aes64esm(rd: Register, rs1: Register, rs2: Register) => aes64esm;
+        emit_aes64ks1i(rd: Register, rs: Register, rnum: u8) => aes64ks1i;
+        emit_aes64ks2(rd: Register, rs1: Register, rs2: Register) => aes64ks2;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn aes64es(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0011001, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn aes64esm(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0011011, rd, rs1, rs2 }.encode()
+}
+
+#[inline]
+pub fn aes64ks1i(rd: Register, rs: Register, rnum: u8) -> u32 {
+    assert!(rnum <= 0xa);
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0011000_10000 | rnum as i16, rd, rs }.encode()
+}
+
+#[inline]
+pub fn aes64ks2(rd: Register, rs1: Register, rs2: Register) -> u32 {
+    RType { opcode: Op, funct3: 0b000, funct7: 0b0111111, rd, rs1, rs2 }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_aes64es() {
+        assert_eq!(0x32c58533, aes64es(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_aes64esm() {
+        assert_eq!(0x36c58533, aes64esm(A0, A1, A2));
+    }
+
+    #[test]
+    fn test_aes64ks1i() {
+        assert_eq!(0x31059513, aes64ks1i(A0, A1, 0));
+        assert_eq!(0x31a59513, aes64ks1i(A0, A1, 10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aes64ks1i_out_of_range() {
+        aes64ks1i(A0, A1, 11);
+    }
+
+    #[test]
+    fn test_aes64ks2() {
+        assert_eq!(0x7ec58533, aes64ks2(A0, A1, A2));
+    }
+}
@@ -0,0 +1,75 @@
+use mitte_core::EmitSlice;
+
+use crate::encoding::*;
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zknh;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_sha512sig0(rd: Register, rs: Register) => sha512sig0;
+        emit_sha512sig1(rd: Register, rs: Register) => sha512sig1;
+        emit_sha512sum0(rd: Register, rs: Register) => sha512sum0;
+        emit_sha512sum1(rd: Register, rs: Register) => sha512sum1;
+    }
+
+    forward! {
+        emit_sha256sig0(rd: Register, rs: Register) => rv32zknh::sha256sig0;
+        emit_sha256sig1(rd: Register, rs: Register) => rv32zknh::sha256sig1;
+        emit_sha256sum0(rd: Register, rs: Register) => rv32zknh::sha256sum0;
+        emit_sha256sum1(rd: Register, rs: Register) => rv32zknh::sha256sum1;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
+
+
+#[inline]
+pub fn sha512sig0(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00110, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sha512sig1(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00111, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sha512sum0(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00100, rd, rs }.encode()
+}
+
+#[inline]
+pub fn sha512sum1(rd: Register, rs: Register) -> u32 {
+    IType { opcode: OpImm, funct3: 0b001, imm12: 0b0001000_00101, rd, rs }.encode()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Register::*;
+
+    #[test]
+    fn test_sha512sig0() {
+        assert_eq!(0x10659513, sha512sig0(A0, A1));
+    }
+
+    #[test]
+    fn test_sha512sig1() {
+        assert_eq!(0x10759513, sha512sig1(A0, A1));
+    }
+
+    #[test]
+    fn test_sha512sum0() {
+        assert_eq!(0x10459513, sha512sum0(A0, A1));
+    }
+
+    #[test]
+    fn test_sha512sum1() {
+        assert_eq!(0x10559513, sha512sum1(A0, A1));
+    }
+}
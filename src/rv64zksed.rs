@@ -0,0 +1,17 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zksed;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_sm4ed(rd: Register, rs1: Register, rs2: Register, bs: u8) => rv32zksed::sm4ed;
+        emit_sm4ks(rd: Register, rs1: Register, rs2: Register, bs: u8) => rv32zksed::sm4ks;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
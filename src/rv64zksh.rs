@@ -0,0 +1,17 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zksh;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_sm3p0(rd: Register, rs: Register) => rv32zksh::sm3p0;
+        emit_sm3p1(rd: Register, rs: Register) => rv32zksh::sm3p1;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
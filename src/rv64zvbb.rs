@@ -0,0 +1,31 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zvbb;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vandn_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32zvbb::vandn_vv;
+        emit_vandn_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32zvbb::vandn_vx;
+        emit_vbrev8_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32zvbb::vbrev8_v;
+        emit_vbrev_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32zvbb::vbrev_v;
+        emit_vclz_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32zvbb::vclz_v;
+        emit_vcpop_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32zvbb::vcpop_v;
+        emit_vctz_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32zvbb::vctz_v;
+        emit_vrev8_v(vd: VRegister, vs2: VRegister, vm: VMask) => rv32zvbb::vrev8_v;
+        emit_vrol_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32zvbb::vrol_vv;
+        emit_vrol_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32zvbb::vrol_vx;
+        emit_vror_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32zvbb::vror_vi;
+        emit_vror_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32zvbb::vror_vv;
+        emit_vror_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32zvbb::vror_vx;
+        emit_vwsll_vi(vd: VRegister, vs2: VRegister, imm: u8, vm: VMask) => rv32zvbb::vwsll_vi;
+        emit_vwsll_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32zvbb::vwsll_vv;
+        emit_vwsll_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32zvbb::vwsll_vx;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
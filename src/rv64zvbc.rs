@@ -0,0 +1,19 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zvbc;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vclmul_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32zvbc::vclmul_vv;
+        emit_vclmul_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32zvbc::vclmul_vx;
+        emit_vclmulh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vm: VMask) => rv32zvbc::vclmulh_vv;
+        emit_vclmulh_vx(vd: VRegister, vs2: VRegister, rs1: Register, vm: VMask) => rv32zvbc::vclmulh_vx;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
@@ -0,0 +1,17 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zvkg;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vghsh_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => rv32zvkg::vghsh_vv;
+        emit_vgmul_vv(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkg::vgmul_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
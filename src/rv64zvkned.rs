@@ -0,0 +1,26 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zvkned;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vaesdf_vs(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesdf_vs;
+        emit_vaesdf_vv(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesdf_vv;
+        emit_vaesdm_vs(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesdm_vs;
+        emit_vaesdm_vv(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesdm_vv;
+        emit_vaesef_vs(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesef_vs;
+        emit_vaesef_vv(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesef_vv;
+        emit_vaesem_vs(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesem_vs;
+        emit_vaesem_vv(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesem_vv;
+        emit_vaeskf1_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => rv32zvkned::vaeskf1_vi;
+        emit_vaeskf2_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => rv32zvkned::vaeskf2_vi;
+        emit_vaesz_vs(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvkned::vaesz_vs;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
@@ -0,0 +1,18 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zvknh;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vsha2ch_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => rv32zvknh::vsha2ch_vv;
+        emit_vsha2cl_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => rv32zvknh::vsha2cl_vv;
+        emit_vsha2ms_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => rv32zvknh::vsha2ms_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
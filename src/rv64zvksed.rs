@@ -0,0 +1,18 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zvksed;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vsm4k_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => rv32zvksed::vsm4k_vi;
+        emit_vsm4r_vs(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvksed::vsm4r_vs;
+        emit_vsm4r_vv(vd: VRegister, vs2: VRegister, vtype: VType) => rv32zvksed::vsm4r_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
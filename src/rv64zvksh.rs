@@ -0,0 +1,17 @@
+use mitte_core::EmitSlice;
+
+use crate::types::*;
+
+use crate::macros::forward;
+
+use crate::rv32zvksh;
+
+
+pub trait Emit: EmitSlice {
+    forward! {
+        emit_vsm3c_vi(vd: VRegister, vs2: VRegister, rnd: u8, vtype: VType) => rv32zvksh::vsm3c_vi;
+        emit_vsm3me_vv(vd: VRegister, vs2: VRegister, vs1: VRegister, vtype: VType) => rv32zvksh::vsm3me_vv;
+    }
+}
+
+impl<E> Emit for E where E: EmitSlice + ?Sized {}
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::ops::{BitOr, BitOrAssign};
 use std::convert::TryFrom;
 use std::error::Error;
 
@@ -58,6 +59,450 @@ impl Register {
 }
 
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FRegister {
+    Ft0, Ft1, Ft2, Ft3,
+    Ft4, Ft5, Ft6, Ft7,
+    Fs0, Fs1, Fa0, Fa1,
+    Fa2, Fa3, Fa4, Fa5,
+    Fa6, Fa7, Fs2, Fs3,
+    Fs4, Fs5, Fs6, Fs7,
+    Fs8, Fs9, Fs10, Fs11,
+    Ft8, Ft9, Ft10, Ft11,
+}
+
+impl FRegister {
+    #[inline]
+    pub fn from_index(index: usize) -> Option<FRegister> {
+        use FRegister::*;
+        match index {
+            0 => Some(Ft0),
+            1 => Some(Ft1),
+            2 => Some(Ft2),
+            3 => Some(Ft3),
+            4 => Some(Ft4),
+            5 => Some(Ft5),
+            6 => Some(Ft6),
+            7 => Some(Ft7),
+            8 => Some(Fs0),
+            9 => Some(Fs1),
+            10 => Some(Fa0),
+            11 => Some(Fa1),
+            12 => Some(Fa2),
+            13 => Some(Fa3),
+            14 => Some(Fa4),
+            15 => Some(Fa5),
+            16 => Some(Fa6),
+            17 => Some(Fa7),
+            18 => Some(Fs2),
+            19 => Some(Fs3),
+            20 => Some(Fs4),
+            21 => Some(Fs5),
+            22 => Some(Fs6),
+            23 => Some(Fs7),
+            24 => Some(Fs8),
+            25 => Some(Fs9),
+            26 => Some(Fs10),
+            27 => Some(Fs11),
+            28 => Some(Ft8),
+            29 => Some(Ft9),
+            30 => Some(Ft10),
+            31 => Some(Ft11),
+            _ => None,
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum VRegister {
+    V0, V1, V2, V3,
+    V4, V5, V6, V7,
+    V8, V9, V10, V11,
+    V12, V13, V14, V15,
+    V16, V17, V18, V19,
+    V20, V21, V22, V23,
+    V24, V25, V26, V27,
+    V28, V29, V30, V31,
+}
+
+impl VRegister {
+    #[inline]
+    pub fn from_index(index: usize) -> Option<VRegister> {
+        use VRegister::*;
+        match index {
+            0 => Some(V0),
+            1 => Some(V1),
+            2 => Some(V2),
+            3 => Some(V3),
+            4 => Some(V4),
+            5 => Some(V5),
+            6 => Some(V6),
+            7 => Some(V7),
+            8 => Some(V8),
+            9 => Some(V9),
+            10 => Some(V10),
+            11 => Some(V11),
+            12 => Some(V12),
+            13 => Some(V13),
+            14 => Some(V14),
+            15 => Some(V15),
+            16 => Some(V16),
+            17 => Some(V17),
+            18 => Some(V18),
+            19 => Some(V19),
+            20 => Some(V20),
+            21 => Some(V21),
+            22 => Some(V22),
+            23 => Some(V23),
+            24 => Some(V24),
+            25 => Some(V25),
+            26 => Some(V26),
+            27 => Some(V27),
+            28 => Some(V28),
+            29 => Some(V29),
+            30 => Some(V30),
+            31 => Some(V31),
+            _ => None,
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RoundingMode {
+    Rne = 0b000,
+    Rtz = 0b001,
+    Rdn = 0b010,
+    Rup = 0b011,
+    Rmm = 0b100,
+    Dyn = 0b111,
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AmoOrdering {
+    Relaxed = 0b00,
+    Release = 0b01,
+    Acquire = 0b10,
+    AcqRel = 0b11,
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum VMask {
+    Masked = 0,
+    Unmasked = 1,
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Sew {
+    E8 = 0b000,
+    E16 = 0b001,
+    E32 = 0b010,
+    E64 = 0b011,
+}
+
+impl Sew {
+    #[inline]
+    pub fn log2(self) -> i8 {
+        self as i8 + 3
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Lmul {
+    Mf8 = 0b101,
+    Mf4 = 0b110,
+    Mf2 = 0b111,
+    M1 = 0b000,
+    M2 = 0b001,
+    M4 = 0b010,
+    M8 = 0b011,
+}
+
+impl Lmul {
+    #[inline]
+    pub fn from_log2(log2: i8) -> Option<Lmul> {
+        match log2 {
+            -3 => Some(Lmul::Mf8),
+            -2 => Some(Lmul::Mf4),
+            -1 => Some(Lmul::Mf2),
+            0 => Some(Lmul::M1),
+            1 => Some(Lmul::M2),
+            2 => Some(Lmul::M4),
+            3 => Some(Lmul::M8),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn log2(self) -> i8 {
+        ((self as i8) << 5) >> 5
+    }
+
+    #[inline]
+    pub fn registers(self) -> usize {
+        1 << self.log2().max(0)
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TailPolicy {
+    Undisturbed = 0,
+    Agnostic = 1,
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MaskPolicy {
+    Undisturbed = 0,
+    Agnostic = 1,
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct VType {
+    pub sew: Sew,
+    pub lmul: Lmul,
+    pub tail: TailPolicy,
+    pub mask: MaskPolicy,
+}
+
+impl VType {
+    #[inline]
+    pub fn new(sew: Sew, lmul: Lmul) -> VType {
+        VType {
+            sew,
+            lmul,
+            tail: TailPolicy::Undisturbed,
+            mask: MaskPolicy::Undisturbed,
+        }
+    }
+
+    #[inline]
+    pub fn tail(self, tail: TailPolicy) -> VType {
+        VType { tail, ..self }
+    }
+
+    #[inline]
+    pub fn mask(self, mask: MaskPolicy) -> VType {
+        VType { mask, ..self }
+    }
+
+    #[inline]
+    pub fn bits(self) -> u16 {
+        (self.mask as u16) << 7
+            | (self.tail as u16) << 6
+            | (self.sew as u16) << 3
+            | self.lmul as u16
+    }
+
+    #[inline]
+    pub fn emul(self, eew: Sew) -> Option<Lmul> {
+        Lmul::from_log2(eew.log2() - self.sew.log2() + self.lmul.log2())
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FenceSet(u8);
+
+impl FenceSet {
+    pub const NONE: FenceSet = FenceSet(0b0000);
+    pub const I: FenceSet = FenceSet(0b1000);
+    pub const O: FenceSet = FenceSet(0b0100);
+    pub const R: FenceSet = FenceSet(0b0010);
+    pub const W: FenceSet = FenceSet(0b0001);
+    pub const RW: FenceSet = FenceSet(0b0011);
+    pub const IO: FenceSet = FenceSet(0b1100);
+    pub const IORW: FenceSet = FenceSet(0b1111);
+
+    #[inline]
+    pub fn from_bits(bits: u8) -> Option<FenceSet> {
+        if bits < 1 << 4 {
+            Some(FenceSet(bits))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    pub fn contains(self, other: FenceSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for FenceSet {
+    type Output = FenceSet;
+
+    #[inline]
+    fn bitor(self, rhs: FenceSet) -> FenceSet {
+        FenceSet(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for FenceSet {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: FenceSet) {
+        self.0 |= rhs.0;
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FliConstant {
+    MinusOne,
+    /// The smallest positive normal number of the destination format.
+    ///
+    /// `TryFrom<f32>` and `TryFrom<f64>` map `f32::MIN_POSITIVE` and `f64::MIN_POSITIVE`
+    /// to this constant; use [`FliConstant::try_from_f16`] for the half-precision
+    /// minimum (2^-14) of `fli.h`.
+    Min,
+    TwoPowMinus16,
+    TwoPowMinus15,
+    TwoPowMinus8,
+    TwoPowMinus7,
+    OneSixteenth,
+    OneEighth,
+    OneQuarter,
+    FiveSixteenths,
+    ThreeEighths,
+    SevenSixteenths,
+    OneHalf,
+    FiveEighths,
+    ThreeQuarters,
+    SevenEighths,
+    One,
+    FiveQuarters,
+    ThreeHalves,
+    SevenQuarters,
+    Two,
+    FiveHalves,
+    Three,
+    Four,
+    Eight,
+    Sixteen,
+    TwoPow7,
+    TwoPow8,
+    TwoPow15,
+    TwoPow16,
+    Infinity,
+    Nan,
+}
+
+const FLI_VALUES: [f64; 32] = [
+    -1.0, f64::MIN_POSITIVE, 1.52587890625e-5, 3.0517578125e-5,
+    3.90625e-3, 7.8125e-3, 0.0625, 0.125,
+    0.25, 0.3125, 0.375, 0.4375,
+    0.5, 0.625, 0.75, 0.875,
+    1.0, 1.25, 1.5, 1.75,
+    2.0, 2.5, 3.0, 4.0,
+    8.0, 16.0, 128.0, 256.0,
+    32768.0, 65536.0, f64::INFINITY, f64::NAN,
+];
+
+const F16_MIN_POSITIVE: f64 = 6.103515625e-5;
+
+impl FliConstant {
+    #[inline]
+    pub fn from_index(index: usize) -> Option<FliConstant> {
+        use FliConstant::*;
+        match index {
+            0 => Some(MinusOne),
+            1 => Some(Min),
+            2 => Some(TwoPowMinus16),
+            3 => Some(TwoPowMinus15),
+            4 => Some(TwoPowMinus8),
+            5 => Some(TwoPowMinus7),
+            6 => Some(OneSixteenth),
+            7 => Some(OneEighth),
+            8 => Some(OneQuarter),
+            9 => Some(FiveSixteenths),
+            10 => Some(ThreeEighths),
+            11 => Some(SevenSixteenths),
+            12 => Some(OneHalf),
+            13 => Some(FiveEighths),
+            14 => Some(ThreeQuarters),
+            15 => Some(SevenEighths),
+            16 => Some(One),
+            17 => Some(FiveQuarters),
+            18 => Some(ThreeHalves),
+            19 => Some(SevenQuarters),
+            20 => Some(Two),
+            21 => Some(FiveHalves),
+            22 => Some(Three),
+            23 => Some(Four),
+            24 => Some(Eight),
+            25 => Some(Sixteen),
+            26 => Some(TwoPow7),
+            27 => Some(TwoPow8),
+            28 => Some(TwoPow15),
+            29 => Some(TwoPow16),
+            30 => Some(Infinity),
+            31 => Some(Nan),
+            _ => None,
+        }
+    }
+
+    /// Converts a half-precision value, widened to `f32`, for use with `fli.h`.
+    #[inline]
+    pub fn try_from_f16(value: f32) -> Result<FliConstant, FliConstantTryFromError> {
+        FliConstant::from_value(value as f64, F16_MIN_POSITIVE)
+            .ok_or(FliConstantTryFromError(value as f64))
+    }
+
+    #[inline]
+    fn from_value(value: f64, min: f64) -> Option<FliConstant> {
+        if value.is_nan() {
+            return Some(FliConstant::Nan);
+        }
+        let mut values = FLI_VALUES;
+        values[1] = min;
+        values.iter().position(|&v| v == value).and_then(FliConstant::from_index)
+    }
+}
+
+impl TryFrom<f32> for FliConstant {
+    type Error = FliConstantTryFromError;
+
+    #[inline]
+    fn try_from(value: f32) -> Result<FliConstant, FliConstantTryFromError> {
+        FliConstant::from_value(value as f64, f32::MIN_POSITIVE as f64)
+            .ok_or(FliConstantTryFromError(value as f64))
+    }
+}
+
+impl TryFrom<f64> for FliConstant {
+    type Error = FliConstantTryFromError;
+
+    #[inline]
+    fn try_from(value: f64) -> Result<FliConstant, FliConstantTryFromError> {
+        FliConstant::from_value(value, f64::MIN_POSITIVE)
+            .ok_or(FliConstantTryFromError(value))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FliConstantTryFromError(f64);
+
+impl fmt::Display for FliConstantTryFromError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not convert {:?} to FliConstant", self.0)
+    }
+}
+
+impl Error for FliConstantTryFromError {}
+
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum CRegister {
     S0, S1, A0, A1,
@@ -129,6 +574,577 @@ impl fmt::Display for CRegisterTryFromError {
 
 impl Error for CRegisterTryFromError {}
 
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CFRegister {
+    Fs0, Fs1, Fa0, Fa1,
+    Fa2, Fa3, Fa4, Fa5,
+}
+
+impl CFRegister {
+    #[inline]
+    pub fn from_c_index(index: usize) -> Option<CFRegister> {
+        use CFRegister::*;
+        match index {
+            0 => Some(Fs0),
+            1 => Some(Fs1),
+            2 => Some(Fa0),
+            3 => Some(Fa1),
+            4 => Some(Fa2),
+            5 => Some(Fa3),
+            6 => Some(Fa4),
+            7 => Some(Fa5),
+            _ => None,
+        }
+    }
+}
+
+
+impl From<CFRegister> for FRegister {
+    #[inline]
+    fn from(reg: CFRegister) -> FRegister {
+        match reg {
+            CFRegister::Fs0 => FRegister::Fs0,
+            CFRegister::Fs1 => FRegister::Fs1,
+            CFRegister::Fa0 => FRegister::Fa0,
+            CFRegister::Fa1 => FRegister::Fa1,
+            CFRegister::Fa2 => FRegister::Fa2,
+            CFRegister::Fa3 => FRegister::Fa3,
+            CFRegister::Fa4 => FRegister::Fa4,
+            CFRegister::Fa5 => FRegister::Fa5,
+        }
+    }
+}
+
+impl TryFrom<FRegister> for CFRegister {
+    type Error = CFRegisterTryFromError;
+
+    #[inline]
+    fn try_from(reg: FRegister) -> Result<CFRegister, CFRegisterTryFromError> {
+        match reg {
+            FRegister::Fs0 => Ok(CFRegister::Fs0),
+            FRegister::Fs1 => Ok(CFRegister::Fs1),
+            FRegister::Fa0 => Ok(CFRegister::Fa0),
+            FRegister::Fa1 => Ok(CFRegister::Fa1),
+            FRegister::Fa2 => Ok(CFRegister::Fa2),
+            FRegister::Fa3 => Ok(CFRegister::Fa3),
+            FRegister::Fa4 => Ok(CFRegister::Fa4),
+            FRegister::Fa5 => Ok(CFRegister::Fa5),
+            _ => Err(CFRegisterTryFromError(reg)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CFRegisterTryFromError(FRegister);
+
+impl fmt::Display for CFRegisterTryFromError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not convert {:?} to CFRegister", self.0)
+    }
+}
+
+impl Error for CFRegisterTryFromError {}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SRegister {
+    S0, S1, S2, S3,
+    S4, S5, S6, S7,
+}
+
+impl SRegister {
+    #[inline]
+    pub fn from_s_index(index: usize) -> Option<SRegister> {
+        use SRegister::*;
+        match index {
+            0 => Some(S0),
+            1 => Some(S1),
+            2 => Some(S2),
+            3 => Some(S3),
+            4 => Some(S4),
+            5 => Some(S5),
+            6 => Some(S6),
+            7 => Some(S7),
+            _ => None,
+        }
+    }
+}
+
+
+impl From<SRegister> for Register {
+    #[inline]
+    fn from(reg: SRegister) -> Register {
+        match reg {
+            SRegister::S0 => Register::S0,
+            SRegister::S1 => Register::S1,
+            SRegister::S2 => Register::S2,
+            SRegister::S3 => Register::S3,
+            SRegister::S4 => Register::S4,
+            SRegister::S5 => Register::S5,
+            SRegister::S6 => Register::S6,
+            SRegister::S7 => Register::S7,
+        }
+    }
+}
+
+impl TryFrom<Register> for SRegister {
+    type Error = SRegisterTryFromError;
+
+    #[inline]
+    fn try_from(reg: Register) -> Result<SRegister, SRegisterTryFromError> {
+        match reg {
+            Register::S0 => Ok(SRegister::S0),
+            Register::S1 => Ok(SRegister::S1),
+            Register::S2 => Ok(SRegister::S2),
+            Register::S3 => Ok(SRegister::S3),
+            Register::S4 => Ok(SRegister::S4),
+            Register::S5 => Ok(SRegister::S5),
+            Register::S6 => Ok(SRegister::S6),
+            Register::S7 => Ok(SRegister::S7),
+            _ => Err(SRegisterTryFromError(reg)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SRegisterTryFromError(Register);
+
+impl fmt::Display for SRegisterTryFromError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not convert {:?} to SRegister", self.0)
+    }
+}
+
+impl Error for SRegisterTryFromError {}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RegisterList {
+    Ra = 4,
+    RaS0 = 5,
+    RaS0S1 = 6,
+    RaS0S2 = 7,
+    RaS0S3 = 8,
+    RaS0S4 = 9,
+    RaS0S5 = 10,
+    RaS0S6 = 11,
+    RaS0S7 = 12,
+    RaS0S8 = 13,
+    RaS0S9 = 14,
+    RaS0S11 = 15,
+}
+
+impl RegisterList {
+    #[inline]
+    pub fn registers(self) -> usize {
+        match self {
+            RegisterList::RaS0S11 => 13,
+            _ => self as usize - 3,
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PrivilegeLevel {
+    User = 0b00,
+    Supervisor = 0b01,
+    Hypervisor = 0b10,
+    Machine = 0b11,
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Csr(u16);
+
+impl Csr {
+    // User
+    pub const FFLAGS: Csr = Csr(0x001);
+    pub const FRM: Csr = Csr(0x002);
+    pub const FCSR: Csr = Csr(0x003);
+    pub const VSTART: Csr = Csr(0x008);
+    pub const VXSAT: Csr = Csr(0x009);
+    pub const VXRM: Csr = Csr(0x00a);
+    pub const VCSR: Csr = Csr(0x00f);
+    pub const SEED: Csr = Csr(0x015);
+    pub const JVT: Csr = Csr(0x017);
+    pub const CYCLE: Csr = Csr(0xc00);
+    pub const TIME: Csr = Csr(0xc01);
+    pub const INSTRET: Csr = Csr(0xc02);
+    pub const HPMCOUNTER3: Csr = Csr(0xc03);
+    pub const HPMCOUNTER4: Csr = Csr(0xc04);
+    pub const HPMCOUNTER5: Csr = Csr(0xc05);
+    pub const HPMCOUNTER6: Csr = Csr(0xc06);
+    pub const HPMCOUNTER7: Csr = Csr(0xc07);
+    pub const HPMCOUNTER8: Csr = Csr(0xc08);
+    pub const HPMCOUNTER9: Csr = Csr(0xc09);
+    pub const HPMCOUNTER10: Csr = Csr(0xc0a);
+    pub const HPMCOUNTER11: Csr = Csr(0xc0b);
+    pub const HPMCOUNTER12: Csr = Csr(0xc0c);
+    pub const HPMCOUNTER13: Csr = Csr(0xc0d);
+    pub const HPMCOUNTER14: Csr = Csr(0xc0e);
+    pub const HPMCOUNTER15: Csr = Csr(0xc0f);
+    pub const HPMCOUNTER16: Csr = Csr(0xc10);
+    pub const HPMCOUNTER17: Csr = Csr(0xc11);
+    pub const HPMCOUNTER18: Csr = Csr(0xc12);
+    pub const HPMCOUNTER19: Csr = Csr(0xc13);
+    pub const HPMCOUNTER20: Csr = Csr(0xc14);
+    pub const HPMCOUNTER21: Csr = Csr(0xc15);
+    pub const HPMCOUNTER22: Csr = Csr(0xc16);
+    pub const HPMCOUNTER23: Csr = Csr(0xc17);
+    pub const HPMCOUNTER24: Csr = Csr(0xc18);
+    pub const HPMCOUNTER25: Csr = Csr(0xc19);
+    pub const HPMCOUNTER26: Csr = Csr(0xc1a);
+    pub const HPMCOUNTER27: Csr = Csr(0xc1b);
+    pub const HPMCOUNTER28: Csr = Csr(0xc1c);
+    pub const HPMCOUNTER29: Csr = Csr(0xc1d);
+    pub const HPMCOUNTER30: Csr = Csr(0xc1e);
+    pub const HPMCOUNTER31: Csr = Csr(0xc1f);
+    pub const VL: Csr = Csr(0xc20);
+    pub const VTYPE: Csr = Csr(0xc21);
+    pub const VLENB: Csr = Csr(0xc22);
+    pub const CYCLEH: Csr = Csr(0xc80);
+    pub const TIMEH: Csr = Csr(0xc81);
+    pub const INSTRETH: Csr = Csr(0xc82);
+    pub const HPMCOUNTER3H: Csr = Csr(0xc83);
+    pub const HPMCOUNTER4H: Csr = Csr(0xc84);
+    pub const HPMCOUNTER5H: Csr = Csr(0xc85);
+    pub const HPMCOUNTER6H: Csr = Csr(0xc86);
+    pub const HPMCOUNTER7H: Csr = Csr(0xc87);
+    pub const HPMCOUNTER8H: Csr = Csr(0xc88);
+    pub const HPMCOUNTER9H: Csr = Csr(0xc89);
+    pub const HPMCOUNTER10H: Csr = Csr(0xc8a);
+    pub const HPMCOUNTER11H: Csr = Csr(0xc8b);
+    pub const HPMCOUNTER12H: Csr = Csr(0xc8c);
+    pub const HPMCOUNTER13H: Csr = Csr(0xc8d);
+    pub const HPMCOUNTER14H: Csr = Csr(0xc8e);
+    pub const HPMCOUNTER15H: Csr = Csr(0xc8f);
+    pub const HPMCOUNTER16H: Csr = Csr(0xc90);
+    pub const HPMCOUNTER17H: Csr = Csr(0xc91);
+    pub const HPMCOUNTER18H: Csr = Csr(0xc92);
+    pub const HPMCOUNTER19H: Csr = Csr(0xc93);
+    pub const HPMCOUNTER20H: Csr = Csr(0xc94);
+    pub const HPMCOUNTER21H: Csr = Csr(0xc95);
+    pub const HPMCOUNTER22H: Csr = Csr(0xc96);
+    pub const HPMCOUNTER23H: Csr = Csr(0xc97);
+    pub const HPMCOUNTER24H: Csr = Csr(0xc98);
+    pub const HPMCOUNTER25H: Csr = Csr(0xc99);
+    pub const HPMCOUNTER26H: Csr = Csr(0xc9a);
+    pub const HPMCOUNTER27H: Csr = Csr(0xc9b);
+    pub const HPMCOUNTER28H: Csr = Csr(0xc9c);
+    pub const HPMCOUNTER29H: Csr = Csr(0xc9d);
+    pub const HPMCOUNTER30H: Csr = Csr(0xc9e);
+    pub const HPMCOUNTER31H: Csr = Csr(0xc9f);
+
+    // Supervisor
+    pub const SSTATUS: Csr = Csr(0x100);
+    pub const SIE: Csr = Csr(0x104);
+    pub const STVEC: Csr = Csr(0x105);
+    pub const SCOUNTEREN: Csr = Csr(0x106);
+    pub const SENVCFG: Csr = Csr(0x10a);
+    pub const SSCRATCH: Csr = Csr(0x140);
+    pub const SEPC: Csr = Csr(0x141);
+    pub const SCAUSE: Csr = Csr(0x142);
+    pub const STVAL: Csr = Csr(0x143);
+    pub const SIP: Csr = Csr(0x144);
+    pub const SATP: Csr = Csr(0x180);
+    pub const SCONTEXT: Csr = Csr(0x5a8);
+
+    // Hypervisor
+    pub const HSTATUS: Csr = Csr(0x600);
+    pub const HEDELEG: Csr = Csr(0x602);
+    pub const HIDELEG: Csr = Csr(0x603);
+    pub const HIE: Csr = Csr(0x604);
+    pub const HTIMEDELTA: Csr = Csr(0x605);
+    pub const HCOUNTEREN: Csr = Csr(0x606);
+    pub const HGEIE: Csr = Csr(0x607);
+    pub const HENVCFG: Csr = Csr(0x60a);
+    pub const HTIMEDELTAH: Csr = Csr(0x615);
+    pub const HENVCFGH: Csr = Csr(0x61a);
+    pub const HTVAL: Csr = Csr(0x643);
+    pub const HIP: Csr = Csr(0x644);
+    pub const HVIP: Csr = Csr(0x645);
+    pub const HTINST: Csr = Csr(0x64a);
+    pub const HGATP: Csr = Csr(0x680);
+    pub const HCONTEXT: Csr = Csr(0x6a8);
+    pub const HGEIP: Csr = Csr(0xe12);
+    pub const VSSTATUS: Csr = Csr(0x200);
+    pub const VSIE: Csr = Csr(0x204);
+    pub const VSTVEC: Csr = Csr(0x205);
+    pub const VSSCRATCH: Csr = Csr(0x240);
+    pub const VSEPC: Csr = Csr(0x241);
+    pub const VSCAUSE: Csr = Csr(0x242);
+    pub const VSTVAL: Csr = Csr(0x243);
+    pub const VSIP: Csr = Csr(0x244);
+    pub const VSATP: Csr = Csr(0x280);
+
+    // Machine
+    pub const MVENDORID: Csr = Csr(0xf11);
+    pub const MARCHID: Csr = Csr(0xf12);
+    pub const MIMPID: Csr = Csr(0xf13);
+    pub const MHARTID: Csr = Csr(0xf14);
+    pub const MCONFIGPTR: Csr = Csr(0xf15);
+    pub const MSTATUS: Csr = Csr(0x300);
+    pub const MISA: Csr = Csr(0x301);
+    pub const MEDELEG: Csr = Csr(0x302);
+    pub const MIDELEG: Csr = Csr(0x303);
+    pub const MIE: Csr = Csr(0x304);
+    pub const MTVEC: Csr = Csr(0x305);
+    pub const MCOUNTEREN: Csr = Csr(0x306);
+    pub const MENVCFG: Csr = Csr(0x30a);
+    pub const MSTATUSH: Csr = Csr(0x310);
+    pub const MENVCFGH: Csr = Csr(0x31a);
+    pub const MCOUNTINHIBIT: Csr = Csr(0x320);
+    pub const MHPMEVENT3: Csr = Csr(0x323);
+    pub const MHPMEVENT4: Csr = Csr(0x324);
+    pub const MHPMEVENT5: Csr = Csr(0x325);
+    pub const MHPMEVENT6: Csr = Csr(0x326);
+    pub const MHPMEVENT7: Csr = Csr(0x327);
+    pub const MHPMEVENT8: Csr = Csr(0x328);
+    pub const MHPMEVENT9: Csr = Csr(0x329);
+    pub const MHPMEVENT10: Csr = Csr(0x32a);
+    pub const MHPMEVENT11: Csr = Csr(0x32b);
+    pub const MHPMEVENT12: Csr = Csr(0x32c);
+    pub const MHPMEVENT13: Csr = Csr(0x32d);
+    pub const MHPMEVENT14: Csr = Csr(0x32e);
+    pub const MHPMEVENT15: Csr = Csr(0x32f);
+    pub const MHPMEVENT16: Csr = Csr(0x330);
+    pub const MHPMEVENT17: Csr = Csr(0x331);
+    pub const MHPMEVENT18: Csr = Csr(0x332);
+    pub const MHPMEVENT19: Csr = Csr(0x333);
+    pub const MHPMEVENT20: Csr = Csr(0x334);
+    pub const MHPMEVENT21: Csr = Csr(0x335);
+    pub const MHPMEVENT22: Csr = Csr(0x336);
+    pub const MHPMEVENT23: Csr = Csr(0x337);
+    pub const MHPMEVENT24: Csr = Csr(0x338);
+    pub const MHPMEVENT25: Csr = Csr(0x339);
+    pub const MHPMEVENT26: Csr = Csr(0x33a);
+    pub const MHPMEVENT27: Csr = Csr(0x33b);
+    pub const MHPMEVENT28: Csr = Csr(0x33c);
+    pub const MHPMEVENT29: Csr = Csr(0x33d);
+    pub const MHPMEVENT30: Csr = Csr(0x33e);
+    pub const MHPMEVENT31: Csr = Csr(0x33f);
+    pub const MSCRATCH: Csr = Csr(0x340);
+    pub const MEPC: Csr = Csr(0x341);
+    pub const MCAUSE: Csr = Csr(0x342);
+    pub const MTVAL: Csr = Csr(0x343);
+    pub const MIP: Csr = Csr(0x344);
+    pub const MTINST: Csr = Csr(0x34a);
+    pub const MTVAL2: Csr = Csr(0x34b);
+    pub const PMPCFG0: Csr = Csr(0x3a0);
+    pub const PMPCFG1: Csr = Csr(0x3a1);
+    pub const PMPCFG2: Csr = Csr(0x3a2);
+    pub const PMPCFG3: Csr = Csr(0x3a3);
+    pub const PMPCFG4: Csr = Csr(0x3a4);
+    pub const PMPCFG5: Csr = Csr(0x3a5);
+    pub const PMPCFG6: Csr = Csr(0x3a6);
+    pub const PMPCFG7: Csr = Csr(0x3a7);
+    pub const PMPCFG8: Csr = Csr(0x3a8);
+    pub const PMPCFG9: Csr = Csr(0x3a9);
+    pub const PMPCFG10: Csr = Csr(0x3aa);
+    pub const PMPCFG11: Csr = Csr(0x3ab);
+    pub const PMPCFG12: Csr = Csr(0x3ac);
+    pub const PMPCFG13: Csr = Csr(0x3ad);
+    pub const PMPCFG14: Csr = Csr(0x3ae);
+    pub const PMPCFG15: Csr = Csr(0x3af);
+    pub const PMPADDR0: Csr = Csr(0x3b0);
+    pub const PMPADDR1: Csr = Csr(0x3b1);
+    pub const PMPADDR2: Csr = Csr(0x3b2);
+    pub const PMPADDR3: Csr = Csr(0x3b3);
+    pub const PMPADDR4: Csr = Csr(0x3b4);
+    pub const PMPADDR5: Csr = Csr(0x3b5);
+    pub const PMPADDR6: Csr = Csr(0x3b6);
+    pub const PMPADDR7: Csr = Csr(0x3b7);
+    pub const PMPADDR8: Csr = Csr(0x3b8);
+    pub const PMPADDR9: Csr = Csr(0x3b9);
+    pub const PMPADDR10: Csr = Csr(0x3ba);
+    pub const PMPADDR11: Csr = Csr(0x3bb);
+    pub const PMPADDR12: Csr = Csr(0x3bc);
+    pub const PMPADDR13: Csr = Csr(0x3bd);
+    pub const PMPADDR14: Csr = Csr(0x3be);
+    pub const PMPADDR15: Csr = Csr(0x3bf);
+    pub const PMPADDR16: Csr = Csr(0x3c0);
+    pub const PMPADDR17: Csr = Csr(0x3c1);
+    pub const PMPADDR18: Csr = Csr(0x3c2);
+    pub const PMPADDR19: Csr = Csr(0x3c3);
+    pub const PMPADDR20: Csr = Csr(0x3c4);
+    pub const PMPADDR21: Csr = Csr(0x3c5);
+    pub const PMPADDR22: Csr = Csr(0x3c6);
+    pub const PMPADDR23: Csr = Csr(0x3c7);
+    pub const PMPADDR24: Csr = Csr(0x3c8);
+    pub const PMPADDR25: Csr = Csr(0x3c9);
+    pub const PMPADDR26: Csr = Csr(0x3ca);
+    pub const PMPADDR27: Csr = Csr(0x3cb);
+    pub const PMPADDR28: Csr = Csr(0x3cc);
+    pub const PMPADDR29: Csr = Csr(0x3cd);
+    pub const PMPADDR30: Csr = Csr(0x3ce);
+    pub const PMPADDR31: Csr = Csr(0x3cf);
+    pub const PMPADDR32: Csr = Csr(0x3d0);
+    pub const PMPADDR33: Csr = Csr(0x3d1);
+    pub const PMPADDR34: Csr = Csr(0x3d2);
+    pub const PMPADDR35: Csr = Csr(0x3d3);
+    pub const PMPADDR36: Csr = Csr(0x3d4);
+    pub const PMPADDR37: Csr = Csr(0x3d5);
+    pub const PMPADDR38: Csr = Csr(0x3d6);
+    pub const PMPADDR39: Csr = Csr(0x3d7);
+    pub const PMPADDR40: Csr = Csr(0x3d8);
+    pub const PMPADDR41: Csr = Csr(0x3d9);
+    pub const PMPADDR42: Csr = Csr(0x3da);
+    pub const PMPADDR43: Csr = Csr(0x3db);
+    pub const PMPADDR44: Csr = Csr(0x3dc);
+    pub const PMPADDR45: Csr = Csr(0x3dd);
+    pub const PMPADDR46: Csr = Csr(0x3de);
+    pub const PMPADDR47: Csr = Csr(0x3df);
+    pub const PMPADDR48: Csr = Csr(0x3e0);
+    pub const PMPADDR49: Csr = Csr(0x3e1);
+    pub const PMPADDR50: Csr = Csr(0x3e2);
+    pub const PMPADDR51: Csr = Csr(0x3e3);
+    pub const PMPADDR52: Csr = Csr(0x3e4);
+    pub const PMPADDR53: Csr = Csr(0x3e5);
+    pub const PMPADDR54: Csr = Csr(0x3e6);
+    pub const PMPADDR55: Csr = Csr(0x3e7);
+    pub const PMPADDR56: Csr = Csr(0x3e8);
+    pub const PMPADDR57: Csr = Csr(0x3e9);
+    pub const PMPADDR58: Csr = Csr(0x3ea);
+    pub const PMPADDR59: Csr = Csr(0x3eb);
+    pub const PMPADDR60: Csr = Csr(0x3ec);
+    pub const PMPADDR61: Csr = Csr(0x3ed);
+    pub const PMPADDR62: Csr = Csr(0x3ee);
+    pub const PMPADDR63: Csr = Csr(0x3ef);
+    pub const MSECCFG: Csr = Csr(0x747);
+    pub const MSECCFGH: Csr = Csr(0x757);
+    pub const MCYCLE: Csr = Csr(0xb00);
+    pub const MINSTRET: Csr = Csr(0xb02);
+    pub const MHPMCOUNTER3: Csr = Csr(0xb03);
+    pub const MHPMCOUNTER4: Csr = Csr(0xb04);
+    pub const MHPMCOUNTER5: Csr = Csr(0xb05);
+    pub const MHPMCOUNTER6: Csr = Csr(0xb06);
+    pub const MHPMCOUNTER7: Csr = Csr(0xb07);
+    pub const MHPMCOUNTER8: Csr = Csr(0xb08);
+    pub const MHPMCOUNTER9: Csr = Csr(0xb09);
+    pub const MHPMCOUNTER10: Csr = Csr(0xb0a);
+    pub const MHPMCOUNTER11: Csr = Csr(0xb0b);
+    pub const MHPMCOUNTER12: Csr = Csr(0xb0c);
+    pub const MHPMCOUNTER13: Csr = Csr(0xb0d);
+    pub const MHPMCOUNTER14: Csr = Csr(0xb0e);
+    pub const MHPMCOUNTER15: Csr = Csr(0xb0f);
+    pub const MHPMCOUNTER16: Csr = Csr(0xb10);
+    pub const MHPMCOUNTER17: Csr = Csr(0xb11);
+    pub const MHPMCOUNTER18: Csr = Csr(0xb12);
+    pub const MHPMCOUNTER19: Csr = Csr(0xb13);
+    pub const MHPMCOUNTER20: Csr = Csr(0xb14);
+    pub const MHPMCOUNTER21: Csr = Csr(0xb15);
+    pub const MHPMCOUNTER22: Csr = Csr(0xb16);
+    pub const MHPMCOUNTER23: Csr = Csr(0xb17);
+    pub const MHPMCOUNTER24: Csr = Csr(0xb18);
+    pub const MHPMCOUNTER25: Csr = Csr(0xb19);
+    pub const MHPMCOUNTER26: Csr = Csr(0xb1a);
+    pub const MHPMCOUNTER27: Csr = Csr(0xb1b);
+    pub const MHPMCOUNTER28: Csr = Csr(0xb1c);
+    pub const MHPMCOUNTER29: Csr = Csr(0xb1d);
+    pub const MHPMCOUNTER30: Csr = Csr(0xb1e);
+    pub const MHPMCOUNTER31: Csr = Csr(0xb1f);
+    pub const MCYCLEH: Csr = Csr(0xb80);
+    pub const MINSTRETH: Csr = Csr(0xb82);
+    pub const MHPMCOUNTER3H: Csr = Csr(0xb83);
+    pub const MHPMCOUNTER4H: Csr = Csr(0xb84);
+    pub const MHPMCOUNTER5H: Csr = Csr(0xb85);
+    pub const MHPMCOUNTER6H: Csr = Csr(0xb86);
+    pub const MHPMCOUNTER7H: Csr = Csr(0xb87);
+    pub const MHPMCOUNTER8H: Csr = Csr(0xb88);
+    pub const MHPMCOUNTER9H: Csr = Csr(0xb89);
+    pub const MHPMCOUNTER10H: Csr = Csr(0xb8a);
+    pub const MHPMCOUNTER11H: Csr = Csr(0xb8b);
+    pub const MHPMCOUNTER12H: Csr = Csr(0xb8c);
+    pub const MHPMCOUNTER13H: Csr = Csr(0xb8d);
+    pub const MHPMCOUNTER14H: Csr = Csr(0xb8e);
+    pub const MHPMCOUNTER15H: Csr = Csr(0xb8f);
+    pub const MHPMCOUNTER16H: Csr = Csr(0xb90);
+    pub const MHPMCOUNTER17H: Csr = Csr(0xb91);
+    pub const MHPMCOUNTER18H: Csr = Csr(0xb92);
+    pub const MHPMCOUNTER19H: Csr = Csr(0xb93);
+    pub const MHPMCOUNTER20H: Csr = Csr(0xb94);
+    pub const MHPMCOUNTER21H: Csr = Csr(0xb95);
+    pub const MHPMCOUNTER22H: Csr = Csr(0xb96);
+    pub const MHPMCOUNTER23H: Csr = Csr(0xb97);
+    pub const MHPMCOUNTER24H: Csr = Csr(0xb98);
+    pub const MHPMCOUNTER25H: Csr = Csr(0xb99);
+    pub const MHPMCOUNTER26H: Csr = Csr(0xb9a);
+    pub const MHPMCOUNTER27H: Csr = Csr(0xb9b);
+    pub const MHPMCOUNTER28H: Csr = Csr(0xb9c);
+    pub const MHPMCOUNTER29H: Csr = Csr(0xb9d);
+    pub const MHPMCOUNTER30H: Csr = Csr(0xb9e);
+    pub const MHPMCOUNTER31H: Csr = Csr(0xb9f);
+    pub const TSELECT: Csr = Csr(0x7a0);
+    pub const TDATA1: Csr = Csr(0x7a1);
+    pub const TDATA2: Csr = Csr(0x7a2);
+    pub const TDATA3: Csr = Csr(0x7a3);
+    pub const MCONTEXT: Csr = Csr(0x7a8);
+    pub const DCSR: Csr = Csr(0x7b0);
+    pub const DPC: Csr = Csr(0x7b1);
+    pub const DSCRATCH0: Csr = Csr(0x7b2);
+    pub const DSCRATCH1: Csr = Csr(0x7b3);
+
+    #[inline]
+    pub fn new(address: u16) -> Option<Csr> {
+        if address < 1 << 12 {
+            Some(Csr(address))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn address(self) -> u16 {
+        self.0
+    }
+
+    #[inline]
+    pub fn privilege(self) -> PrivilegeLevel {
+        match (self.0 >> 8) & 0b11 {
+            0b00 => PrivilegeLevel::User,
+            0b01 => PrivilegeLevel::Supervisor,
+            0b10 => PrivilegeLevel::Hypervisor,
+            _ => PrivilegeLevel::Machine,
+        }
+    }
+
+    #[inline]
+    pub fn is_read_only(self) -> bool {
+        (self.0 >> 10) & 0b11 == 0b11
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SeedStatus {
+    Bist = 0b00,
+    Wait = 0b01,
+    Es16 = 0b10,
+    Dead = 0b11,
+}
+
+impl SeedStatus {
+    #[inline]
+    pub fn from_seed(seed: u32) -> SeedStatus {
+        match seed >> 30 {
+            0b00 => SeedStatus::Bist,
+            0b01 => SeedStatus::Wait,
+            0b10 => SeedStatus::Es16,
+            _ => SeedStatus::Dead,
+        }
+    }
+
+    #[inline]
+    pub fn entropy(seed: u32) -> Option<u16> {
+        match SeedStatus::from_seed(seed) {
+            SeedStatus::Es16 => Some(seed as u16),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,10 +1156,132 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fregister_from_index() {
+        for i in 0..32 {
+            assert_eq!(i, FRegister::from_index(i).unwrap() as usize);
+        }
+    }
+
+    #[test]
+    fn test_vregister_from_index() {
+        for i in 0..32 {
+            assert_eq!(i, VRegister::from_index(i).unwrap() as usize);
+        }
+    }
+
+    #[test]
+    fn test_fli_constant_from_index() {
+        for i in 0..32 {
+            assert_eq!(i, FliConstant::from_index(i).unwrap() as usize);
+        }
+    }
+
+    #[test]
+    fn test_fli_constant_try_from() {
+        assert_eq!(Ok(FliConstant::MinusOne), FliConstant::try_from(-1.0f64));
+        assert_eq!(Ok(FliConstant::Min), FliConstant::try_from(f32::MIN_POSITIVE));
+        assert_eq!(Ok(FliConstant::Min), FliConstant::try_from(f64::MIN_POSITIVE));
+        assert_eq!(Ok(FliConstant::TwoPowMinus16), FliConstant::try_from(1.0f64 / 65536.0));
+        assert_eq!(Ok(FliConstant::SevenSixteenths), FliConstant::try_from(0.4375f32));
+        assert_eq!(Ok(FliConstant::TwoPow16), FliConstant::try_from(65536.0f64));
+        assert_eq!(Ok(FliConstant::Infinity), FliConstant::try_from(f64::INFINITY));
+        assert_eq!(Ok(FliConstant::Nan), FliConstant::try_from(f32::NAN));
+        assert!(FliConstant::try_from(0.0f64).is_err());
+        assert!(FliConstant::try_from(f64::NEG_INFINITY).is_err());
+        assert!(FliConstant::try_from(f32::MIN_POSITIVE as f64).is_err());
+    }
+
+    #[test]
+    fn test_fli_constant_try_from_f16() {
+        assert_eq!(Ok(FliConstant::Min), FliConstant::try_from_f16(1.0 / 16384.0));
+        assert_eq!(Ok(FliConstant::TwoPowMinus16), FliConstant::try_from_f16(1.0 / 65536.0));
+        assert_eq!(Ok(FliConstant::One), FliConstant::try_from_f16(1.0));
+        assert_eq!(Ok(FliConstant::Nan), FliConstant::try_from_f16(f32::NAN));
+        assert!(FliConstant::try_from_f16(f32::MIN_POSITIVE).is_err());
+        assert!(FliConstant::try_from(1.0f32 / 16384.0).is_err());
+    }
+
+    #[test]
+    fn test_seed_status() {
+        assert_eq!(SeedStatus::Bist, SeedStatus::from_seed(0x0000_1234));
+        assert_eq!(SeedStatus::Wait, SeedStatus::from_seed(0x4000_0000));
+        assert_eq!(SeedStatus::Es16, SeedStatus::from_seed(0x8000_beef));
+        assert_eq!(SeedStatus::Dead, SeedStatus::from_seed(0xc000_0000));
+        assert_eq!(Some(0xbeef), SeedStatus::entropy(0x8000_beef));
+        assert_eq!(None, SeedStatus::entropy(0x4000_beef));
+    }
+
     #[test]
     fn test_cregister_from_c_index() {
         for i in 0..8 {
             assert_eq!(i, CRegister::from_c_index(i).unwrap() as usize);
         }
     }
+
+    #[test]
+    fn test_cfregister_from_c_index() {
+        for i in 0..8 {
+            assert_eq!(i, CFRegister::from_c_index(i).unwrap() as usize);
+        }
+    }
+
+    #[test]
+    fn test_sregister_from_s_index() {
+        for i in 0..8 {
+            assert_eq!(i, SRegister::from_s_index(i).unwrap() as usize);
+        }
+    }
+
+    #[test]
+    fn test_csr_metadata() {
+        assert_eq!(PrivilegeLevel::User, Csr::FCSR.privilege());
+        assert_eq!(PrivilegeLevel::User, Csr::CYCLE.privilege());
+        assert_eq!(PrivilegeLevel::Supervisor, Csr::SATP.privilege());
+        assert_eq!(PrivilegeLevel::Hypervisor, Csr::HSTATUS.privilege());
+        assert_eq!(PrivilegeLevel::Hypervisor, Csr::VSATP.privilege());
+        assert_eq!(PrivilegeLevel::Machine, Csr::MSTATUS.privilege());
+        assert_eq!(PrivilegeLevel::Machine, Csr::MHARTID.privilege());
+
+        assert!(!Csr::FCSR.is_read_only());
+        assert!(Csr::CYCLE.is_read_only());
+        assert!(Csr::HGEIP.is_read_only());
+        assert!(!Csr::MSTATUS.is_read_only());
+        assert!(Csr::MHARTID.is_read_only());
+
+        assert_eq!(Some(Csr::MSTATUS), Csr::new(0x300));
+        assert_eq!(None, Csr::new(0x1000));
+    }
+
+    #[test]
+    fn test_fence_set() {
+        assert_eq!(FenceSet::RW, FenceSet::R | FenceSet::W);
+        assert_eq!(FenceSet::IORW, FenceSet::IO | FenceSet::RW);
+        assert!(FenceSet::IORW.contains(FenceSet::O));
+        assert!(!FenceSet::RW.contains(FenceSet::I));
+        assert_eq!(Some(FenceSet::IORW), FenceSet::from_bits(0b1111));
+        assert_eq!(None, FenceSet::from_bits(0b10000));
+    }
+
+    #[test]
+    fn test_lmul_log2() {
+        for log2 in -3..=3 {
+            assert_eq!(log2, Lmul::from_log2(log2).unwrap().log2());
+        }
+        assert_eq!(None, Lmul::from_log2(4));
+        assert_eq!(1, Lmul::Mf2.registers());
+        assert_eq!(8, Lmul::M8.registers());
+    }
+
+    #[test]
+    fn test_vtype() {
+        assert_eq!(0b000_010_000, VType::new(Sew::E32, Lmul::M1).bits());
+        assert_eq!(0b011_011_111, VType::new(Sew::E64, Lmul::Mf2)
+            .tail(TailPolicy::Agnostic)
+            .mask(MaskPolicy::Agnostic)
+            .bits());
+        assert_eq!(Some(Lmul::M4), VType::new(Sew::E8, Lmul::M1).emul(Sew::E32));
+        assert_eq!(Some(Lmul::Mf4), VType::new(Sew::E32, Lmul::M1).emul(Sew::E8));
+        assert_eq!(None, VType::new(Sew::E8, Lmul::M8).emul(Sew::E16));
+    }
 }
@@ -236,3 +236,81 @@ impl TestCases for CRegister {
         ]
     }
 }
+
+impl TestCases for CFRegister {
+    fn test_cases() -> Vec<(Self, String)> {
+        vec![
+            (CFRegister::Fs0, "fs0".into()),
+            (CFRegister::Fs1, "fs1".into()),
+            (CFRegister::Fa0, "fa0".into()),
+            (CFRegister::Fa1, "fa1".into()),
+            (CFRegister::Fa2, "fa2".into()),
+            (CFRegister::Fa3, "fa3".into()),
+            (CFRegister::Fa4, "fa4".into()),
+            (CFRegister::Fa5, "fa5".into()),
+        ]
+    }
+}
+
+impl TestCases for FRegister {
+    fn test_cases() -> Vec<(Self, String)> {
+        vec![
+            (FRegister::Ft0, "ft0".into()),
+            (FRegister::Ft1, "ft1".into()),
+            (FRegister::Fs0, "fs0".into()),
+            (FRegister::Fa0, "fa0".into()),
+            (FRegister::Fa7, "fa7".into()),
+            (FRegister::Ft11, "ft11".into()),
+        ]
+    }
+}
+
+impl TestCases for RoundingMode {
+    fn test_cases() -> Vec<(Self, String)> {
+        vec![
+            (RoundingMode::Rne, ", rne".into()),
+            (RoundingMode::Rtz, ", rtz".into()),
+            (RoundingMode::Rdn, ", rdn".into()),
+            (RoundingMode::Rup, ", rup".into()),
+            (RoundingMode::Rmm, ", rmm".into()),
+            (RoundingMode::Dyn, "".into()),
+        ]
+    }
+}
+
+impl TestCases for AmoOrdering {
+    fn test_cases() -> Vec<(Self, String)> {
+        vec![
+            (AmoOrdering::Relaxed, "".into()),
+            (AmoOrdering::Acquire, ".aq".into()),
+            (AmoOrdering::Release, ".rl".into()),
+            (AmoOrdering::AcqRel, ".aqrl".into()),
+        ]
+    }
+}
+
+impl TestCases for FenceSet {
+    fn test_cases() -> Vec<(Self, String)> {
+        vec![
+            (FenceSet::I, "i".into()),
+            (FenceSet::O, "o".into()),
+            (FenceSet::R, "r".into()),
+            (FenceSet::W, "w".into()),
+            (FenceSet::RW, "rw".into()),
+            (FenceSet::IO, "io".into()),
+            (FenceSet::IORW, "iorw".into()),
+        ]
+    }
+}
+
+impl TestCases for Csr {
+    fn test_cases() -> Vec<(Self, String)> {
+        vec![
+            (Csr::SSCRATCH, "sscratch".into()),
+            (Csr::SATP, "satp".into()),
+            (Csr::MSTATUS, "mstatus".into()),
+            (Csr::MSCRATCH, "mscratch".into()),
+            (Csr::MEPC, "mepc".into()),
+        ]
+    }
+}
@@ -167,6 +167,101 @@ fn test3<A1, A2, A3>(mnemonic: &str, f: fn(A1, A2, A3) -> u32)
     test3_filter(mnemonic, f, |_, _, _| true);
 }
 
+#[track_caller]
+fn test3_format<A1, A2, A3>(
+    mnemonic: &str,
+    f: fn(A1, A2, A3) -> u32,
+    s: impl Fn(&str, &str, &str) -> String
+)
+    where A1: TestCases, A2: TestCases, A3: TestCases
+{
+    test3_format_filter(mnemonic, f, s, |_, _, _| true);
+}
+
+#[track_caller]
+fn test4_format_filter<A1, A2, A3, A4>(
+    mnemonic: &str,
+    f: fn(A1, A2, A3, A4) -> u32,
+    s: impl Fn(&str, &str, &str, &str) -> String,
+    mut filter: impl FnMut(A1, A2, A3, A4) -> bool
+)
+    where A1: TestCases, A2: TestCases, A3: TestCases, A4: TestCases
+{
+    for (a1, s1) in A1::test_cases() {
+        for (a2, s2) in A2::test_cases() {
+            for (a3, s3) in A3::test_cases() {
+                for (a4, s4) in A4::test_cases() {
+                    if filter(a1, a2, a3, a4) {
+                        test_disasm(mnemonic, s(&s1, &s2, &s3, &s4),
+                            &f(a1, a2, a3, a4).to_le_bytes());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[track_caller]
+fn test4_format<A1, A2, A3, A4>(
+    mnemonic: &str,
+    f: fn(A1, A2, A3, A4) -> u32,
+    s: impl Fn(&str, &str, &str, &str) -> String
+)
+    where A1: TestCases, A2: TestCases, A3: TestCases, A4: TestCases
+{
+    test4_format_filter(mnemonic, f, s, |_, _, _, _| true);
+}
+
+#[track_caller]
+fn test5_format<A1, A2, A3, A4, A5>(
+    mnemonic: &str,
+    f: fn(A1, A2, A3, A4, A5) -> u32,
+    s: impl Fn(&str, &str, &str, &str, &str) -> String
+)
+    where A1: TestCases, A2: TestCases, A3: TestCases, A4: TestCases, A5: TestCases
+{
+    for (a1, s1) in A1::test_cases() {
+        for (a2, s2) in A2::test_cases() {
+            for (a3, s3) in A3::test_cases() {
+                for (a4, s4) in A4::test_cases() {
+                    for (a5, s5) in A5::test_cases() {
+                        test_disasm(mnemonic, s(&s1, &s2, &s3, &s4, &s5),
+                            &f(a1, a2, a3, a4, a5).to_le_bytes());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[track_caller]
+fn test_lr(mnemonic: &str, f: fn(Register, Register, AmoOrdering) -> u32) {
+    for (ordering, suffix) in AmoOrdering::test_cases() {
+        for (rd, s1) in Register::test_cases() {
+            for (base, s2) in Register::test_cases() {
+                test_disasm(&format!("{}{}", mnemonic, suffix),
+                    format!("{}, ({})", s1, s2),
+                    &f(rd, base, ordering).to_le_bytes());
+            }
+        }
+    }
+}
+
+#[track_caller]
+fn test_amo(mnemonic: &str, f: fn(Register, Register, Register, AmoOrdering) -> u32) {
+    for (ordering, suffix) in AmoOrdering::test_cases() {
+        for (rd, s1) in Register::test_cases() {
+            for (rs, s2) in Register::test_cases() {
+                for (base, s3) in Register::test_cases() {
+                    test_disasm(&format!("{}{}", mnemonic, suffix),
+                        format!("{}, {}, ({})", s1, s2, s3),
+                        &f(rd, rs, base, ordering).to_le_bytes());
+                }
+            }
+        }
+    }
+}
+
 #[track_caller]
 fn test_ldst_filter<A1, A2, A3, R, const N: usize>(
     mnemonic: &str,
@@ -198,6 +293,51 @@ fn test_addi() {
     test3_filter("addi", rv32i::addi, |_, _, imm12| imm12 != 0);
 }
 
+#[test]
+fn test_amoadd_w() {
+    test_amo("amoadd.w", rv32a::amoadd_w);
+}
+
+#[test]
+fn test_amoand_w() {
+    test_amo("amoand.w", rv32a::amoand_w);
+}
+
+#[test]
+fn test_amomax_w() {
+    test_amo("amomax.w", rv32a::amomax_w);
+}
+
+#[test]
+fn test_amomaxu_w() {
+    test_amo("amomaxu.w", rv32a::amomaxu_w);
+}
+
+#[test]
+fn test_amomin_w() {
+    test_amo("amomin.w", rv32a::amomin_w);
+}
+
+#[test]
+fn test_amominu_w() {
+    test_amo("amominu.w", rv32a::amominu_w);
+}
+
+#[test]
+fn test_amoor_w() {
+    test_amo("amoor.w", rv32a::amoor_w);
+}
+
+#[test]
+fn test_amoswap_w() {
+    test_amo("amoswap.w", rv32a::amoswap_w);
+}
+
+#[test]
+fn test_amoxor_w() {
+    test_amo("amoxor.w", rv32a::amoxor_w);
+}
+
 #[test]
 fn test_and() {
     test3("and", rv32i::and);
@@ -230,6 +370,11 @@ fn test_bgeu() {
     test3_filter("bgeu", rv32i::bgeu, |_, _, offset| offset & 1 == 0);
 }
 
+#[test]
+fn test_bgtz() {
+    test2_filter("bgtz", rv32i::bgtz, |rs, offset| rs != Zero && offset & 1 == 0);
+}
+
 #[test]
 fn test_blt() {
     test3_filter("blt", rv32i::blt, |rs1, rs2, offset| {
@@ -242,6 +387,11 @@ fn test_bltu() {
     test3_filter("bltu", rv32i::bltu, |_, _, offset| offset & 1 == 0);
 }
 
+#[test]
+fn test_bltz() {
+    test2_filter("bltz", rv32i::bltz, |rs, offset| rs != Zero && offset & 1 == 0);
+}
+
 #[test]
 fn test_bne() {
     test3_filter("bne", rv32i::bne, |_, rs2, offset| rs2 != Zero && offset & 1 == 0);
@@ -304,6 +454,62 @@ fn test_c_ebreak() {
     test0("c.ebreak", rv32c::ebreak);
 }
 
+#[test]
+fn test_c_fld() {
+    test_ldst_filter("c.fld", rv32c::fld, |_, _, offset| {
+        offset & 7 == 0 && offset < 0x80
+    });
+}
+
+#[test]
+fn test_c_fldsp() {
+    test2_format_filter("c.fldsp", rv32c::fldsp,
+        |rd, offset| format!("{}, {}(sp)", rd, offset),
+        |_, offset| offset & 7 == 0 && offset < 0x200);
+}
+
+#[test]
+fn test_c_fsd() {
+    test_ldst_filter("c.fsd", rv32c::fsd, |_, _, offset| {
+        offset & 7 == 0 && offset < 0x80
+    });
+}
+
+#[test]
+fn test_c_fsdsp() {
+    test2_format_filter("c.fsdsp", rv32c::fsdsp,
+        |rs, offset| format!("{}, {}(sp)", rs, offset),
+        |_, offset| offset & 7 == 0 && offset < 0x200);
+}
+
+#[test]
+fn test_c_flw() {
+    test_ldst_filter("c.flw", rv32c::flw, |_, _, offset| {
+        offset & 3 == 0 && offset < 0x80
+    });
+}
+
+#[test]
+fn test_c_flwsp() {
+    test2_format_filter("c.flwsp", rv32c::flwsp,
+        |rd, offset| format!("{}, {}(sp)", rd, offset),
+        |_, offset| offset & 3 == 0);
+}
+
+#[test]
+fn test_c_fsw() {
+    test_ldst_filter("c.fsw", rv32c::fsw, |_, _, offset| {
+        offset & 3 == 0 && offset < 0x80
+    });
+}
+
+#[test]
+fn test_c_fswsp() {
+    test2_format_filter("c.fswsp", rv32c::fswsp,
+        |rs, offset| format!("{}, {}(sp)", rs, offset),
+        |_, offset| offset & 3 == 0);
+}
+
 #[test]
 fn test_c_j() {
     test1_filter("c.j", rv32c::j, |offset| {
@@ -311,6 +517,13 @@ fn test_c_j() {
     });
 }
 
+#[test]
+fn test_c_jal() {
+    test1_filter("c.jal", rv32c::jal, |offset| {
+        offset & 1 == 0
+    });
+}
+
 #[test]
 fn test_c_jalr() {
     test1_filter("c.jalr", rv32c::jalr, |rs| rs != Zero);
@@ -417,6 +630,107 @@ fn test_c_xor() {
     test2("c.xor", rv32c::xor);
 }
 
+#[test]
+fn test_csrc() {
+    test2("csrc", rv32zicsr::csrc);
+}
+
+#[test]
+fn test_csrci() {
+    test2_filter("csrci", rv32zicsr::csrci, |_, imm| imm < 32);
+}
+
+#[test]
+fn test_csrr() {
+    test2("csrr", rv32zicsr::csrr);
+}
+
+#[test]
+fn test_csrrc() {
+    test3_filter("csrrc", rv32zicsr::csrrc, |rd, _, _| rd != Zero);
+}
+
+#[test]
+fn test_csrrci() {
+    test3_filter("csrrci", rv32zicsr::csrrci, |rd, _, imm| rd != Zero && imm < 32);
+}
+
+#[test]
+#[should_panic]
+fn test_csrrci_read_only() {
+    rv32zicsr::csrrci(A0, Csr::CYCLE, 1);
+}
+
+#[test]
+#[should_panic]
+fn test_csrrc_read_only() {
+    rv32zicsr::csrrc(A0, Csr::CYCLE, A1);
+}
+
+#[test]
+fn test_csrrs() {
+    test3_filter("csrrs", rv32zicsr::csrrs, |rd, _, rs| rd != Zero && rs != Zero);
+}
+
+#[test]
+fn test_csrrsi() {
+    test3_filter("csrrsi", rv32zicsr::csrrsi, |rd, _, imm| rd != Zero && imm < 32);
+}
+
+#[test]
+#[should_panic]
+fn test_csrrsi_read_only() {
+    rv32zicsr::csrrsi(A0, Csr::CYCLE, 1);
+}
+
+#[test]
+#[should_panic]
+fn test_csrrs_read_only() {
+    rv32zicsr::csrrs(A0, Csr::CYCLE, A1);
+}
+
+#[test]
+fn test_csrrw() {
+    test3_filter("csrrw", rv32zicsr::csrrw, |rd, _, _| rd != Zero);
+}
+
+#[test]
+fn test_csrrwi() {
+    test3_filter("csrrwi", rv32zicsr::csrrwi, |rd, _, imm| rd != Zero && imm < 32);
+}
+
+#[test]
+#[should_panic]
+fn test_csrrwi_read_only() {
+    rv32zicsr::csrrwi(A0, Csr::CYCLE, 0);
+}
+
+#[test]
+#[should_panic]
+fn test_csrrw_read_only() {
+    rv32zicsr::csrrw(A0, Csr::CYCLE, A1);
+}
+
+#[test]
+fn test_csrs() {
+    test2_filter("csrs", rv32zicsr::csrs, |_, rs| rs != Zero);
+}
+
+#[test]
+fn test_csrsi() {
+    test2_filter("csrsi", rv32zicsr::csrsi, |_, imm| imm < 32);
+}
+
+#[test]
+fn test_csrw() {
+    test2("csrw", rv32zicsr::csrw);
+}
+
+#[test]
+fn test_csrwi() {
+    test2_filter("csrwi", rv32zicsr::csrwi, |_, imm| imm < 32);
+}
+
 #[test]
 fn test_div() {
     test3("div", rv32m::div);
@@ -427,6 +741,11 @@ fn test_divu() {
     test3("divu", rv32m::divu);
 }
 
+#[test]
+fn test_dret() {
+    assert_eq!(0x7b200073, rv32priv::dret());
+}
+
 #[test]
 fn test_ebreak() {
     test0("ebreak", rv32i::ebreak);
@@ -437,6 +756,323 @@ fn test_ecall() {
     test0("ecall", rv32i::ecall);
 }
 
+#[test]
+fn test_fabs_d() {
+    test2("fabs.d", rv32d::fabs_d);
+}
+
+#[test]
+fn test_fabs_s() {
+    test2("fabs.s", rv32f::fabs_s);
+}
+
+#[test]
+fn test_fadd_d() {
+    test4_format("fadd.d", rv32d::fadd_d,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fadd_s() {
+    test4_format("fadd.s", rv32f::fadd_s,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fclass_d() {
+    test2("fclass.d", rv32d::fclass_d);
+}
+
+#[test]
+fn test_fclass_s() {
+    test2("fclass.s", rv32f::fclass_s);
+}
+
+#[test]
+fn test_fcvt_d_s() {
+    test2("fcvt.d.s", rv32d::fcvt_d_s);
+}
+
+#[test]
+fn test_fcvt_d_w() {
+    test2("fcvt.d.w", rv32d::fcvt_d_w);
+}
+
+#[test]
+fn test_fcvt_d_wu() {
+    test2("fcvt.d.wu", rv32d::fcvt_d_wu);
+}
+
+#[test]
+fn test_fcvt_s_d() {
+    test3_format("fcvt.s.d", rv32d::fcvt_s_d, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fcvt_s_w() {
+    test3_format("fcvt.s.w", rv32f::fcvt_s_w, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fcvt_s_wu() {
+    test3_format("fcvt.s.wu", rv32f::fcvt_s_wu, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fcvt_w_d() {
+    test3_format("fcvt.w.d", rv32d::fcvt_w_d, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fcvt_w_s() {
+    test3_format("fcvt.w.s", rv32f::fcvt_w_s, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fcvt_wu_d() {
+    test3_format("fcvt.wu.d", rv32d::fcvt_wu_d, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fcvt_wu_s() {
+    test3_format("fcvt.wu.s", rv32f::fcvt_wu_s, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fdiv_d() {
+    test4_format("fdiv.d", rv32d::fdiv_d,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fdiv_s() {
+    test4_format("fdiv.s", rv32f::fdiv_s,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fence() {
+    test2_filter("fence", rv32i::fence,
+        |pred, succ| pred != FenceSet::IORW || succ != FenceSet::IORW);
+}
+
+#[test]
+fn test_fence_i() {
+    test0("fence.i", rv32zifencei::fence_i);
+}
+
+#[test]
+fn test_feq_d() {
+    test3("feq.d", rv32d::feq_d);
+}
+
+#[test]
+fn test_feq_s() {
+    test3("feq.s", rv32f::feq_s);
+}
+
+#[test]
+fn test_fld() {
+    test_ldst("fld", rv32d::fld);
+}
+
+#[test]
+fn test_fle_d() {
+    test3("fle.d", rv32d::fle_d);
+}
+
+#[test]
+fn test_fle_s() {
+    test3("fle.s", rv32f::fle_s);
+}
+
+#[test]
+fn test_flt_d() {
+    test3("flt.d", rv32d::flt_d);
+}
+
+#[test]
+fn test_flt_s() {
+    test3("flt.s", rv32f::flt_s);
+}
+
+#[test]
+fn test_flw() {
+    test_ldst("flw", rv32f::flw);
+}
+
+#[test]
+fn test_fmadd_d() {
+    test5_format("fmadd.d", rv32d::fmadd_d,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fmadd_s() {
+    test5_format("fmadd.s", rv32f::fmadd_s,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fmax_d() {
+    test3("fmax.d", rv32d::fmax_d);
+}
+
+#[test]
+fn test_fmax_s() {
+    test3("fmax.s", rv32f::fmax_s);
+}
+
+#[test]
+fn test_fmin_d() {
+    test3("fmin.d", rv32d::fmin_d);
+}
+
+#[test]
+fn test_fmin_s() {
+    test3("fmin.s", rv32f::fmin_s);
+}
+
+#[test]
+fn test_fmsub_d() {
+    test5_format("fmsub.d", rv32d::fmsub_d,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fmsub_s() {
+    test5_format("fmsub.s", rv32f::fmsub_s,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fmul_d() {
+    test4_format("fmul.d", rv32d::fmul_d,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fmul_s() {
+    test4_format("fmul.s", rv32f::fmul_s,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fmv_d() {
+    test2("fmv.d", rv32d::fmv_d);
+}
+
+#[test]
+fn test_fmv_s() {
+    test2("fmv.s", rv32f::fmv_s);
+}
+
+#[test]
+fn test_fmv_w_x() {
+    test2("fmv.w.x", rv32f::fmv_w_x);
+}
+
+#[test]
+fn test_fmv_x_w() {
+    test2("fmv.x.w", rv32f::fmv_x_w);
+}
+
+#[test]
+fn test_fneg_d() {
+    test2("fneg.d", rv32d::fneg_d);
+}
+
+#[test]
+fn test_fneg_s() {
+    test2("fneg.s", rv32f::fneg_s);
+}
+
+#[test]
+fn test_fnmadd_d() {
+    test5_format("fnmadd.d", rv32d::fnmadd_d,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fnmadd_s() {
+    test5_format("fnmadd.s", rv32f::fnmadd_s,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fnmsub_d() {
+    test5_format("fnmsub.d", rv32d::fnmsub_d,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fnmsub_s() {
+    test5_format("fnmsub.s", rv32f::fnmsub_s,
+        |rd, rs1, rs2, rs3, rm| format!("{}, {}, {}, {}{}", rd, rs1, rs2, rs3, rm));
+}
+
+#[test]
+fn test_fsd() {
+    test_ldst("fsd", rv32d::fsd);
+}
+
+#[test]
+fn test_fsgnj_d() {
+    test3_filter("fsgnj.d", rv32d::fsgnj_d, |_, rs1, rs2| rs1 != rs2);
+}
+
+#[test]
+fn test_fsgnj_s() {
+    test3_filter("fsgnj.s", rv32f::fsgnj_s, |_, rs1, rs2| rs1 != rs2);
+}
+
+#[test]
+fn test_fsgnjn_d() {
+    test3_filter("fsgnjn.d", rv32d::fsgnjn_d, |_, rs1, rs2| rs1 != rs2);
+}
+
+#[test]
+fn test_fsgnjn_s() {
+    test3_filter("fsgnjn.s", rv32f::fsgnjn_s, |_, rs1, rs2| rs1 != rs2);
+}
+
+#[test]
+fn test_fsgnjx_d() {
+    test3_filter("fsgnjx.d", rv32d::fsgnjx_d, |_, rs1, rs2| rs1 != rs2);
+}
+
+#[test]
+fn test_fsgnjx_s() {
+    test3_filter("fsgnjx.s", rv32f::fsgnjx_s, |_, rs1, rs2| rs1 != rs2);
+}
+
+#[test]
+fn test_fsqrt_d() {
+    test3_format("fsqrt.d", rv32d::fsqrt_d, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fsqrt_s() {
+    test3_format("fsqrt.s", rv32f::fsqrt_s, |rd, rs, rm| format!("{}, {}{}", rd, rs, rm));
+}
+
+#[test]
+fn test_fsub_d() {
+    test4_format("fsub.d", rv32d::fsub_d,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fsub_s() {
+    test4_format("fsub.s", rv32f::fsub_s,
+        |rd, rs1, rs2, rm| format!("{}, {}, {}{}", rd, rs1, rs2, rm));
+}
+
+#[test]
+fn test_fsw() {
+    test_ldst("fsw", rv32f::fsw);
+}
+
 #[test]
 fn test_j() {
     test1_filter("j", rv32i::j, |offset| {
@@ -483,6 +1119,11 @@ fn test_lhu() {
     test_ldst("lhu", rv32i::lhu);
 }
 
+#[test]
+fn test_lr_w() {
+    test_lr("lr.w", rv32a::lr_w);
+}
+
 #[test]
 fn test_lui() {
     test2("lui", rv32i::lui);
@@ -493,6 +1134,11 @@ fn test_lw() {
     test_ldst("lw", rv32i::lw);
 }
 
+#[test]
+fn test_mret() {
+    test0("mret", rv32priv::mret);
+}
+
 #[test]
 fn test_mul() {
     test3("mul", rv32m::mul);
@@ -558,16 +1204,45 @@ fn test_ret() {
     test0("ret", rv32i::ret);
 }
 
+#[test]
+fn test_sc_w() {
+    test_amo("sc.w", rv32a::sc_w);
+}
+
 #[test]
 fn test_seqz() {
     test2("seqz", rv32i::seqz);
 }
 
+#[test]
+fn test_sfence_inval_ir() {
+    assert_eq!(0x18100073, rv32priv::sfence_inval_ir());
+}
+
+#[test]
+fn test_sfence_vma() {
+    test_disasm("sfence.vma", "", &rv32priv::sfence_vma(None, None).to_le_bytes());
+    test_disasm("sfence.vma", "a0", &rv32priv::sfence_vma(Some(A0), None).to_le_bytes());
+    test_disasm("sfence.vma", "a0, a1", &rv32priv::sfence_vma(Some(A0), Some(A1)).to_le_bytes());
+}
+
+#[test]
+fn test_sfence_w_inval() {
+    assert_eq!(0x18000073, rv32priv::sfence_w_inval());
+}
+
 #[test]
 fn test_sgtz() {
     test2_filter("sgtz", rv32i::sgtz, |_, rs| rs != Zero);
 }
 
+#[test]
+fn test_sinval_vma() {
+    assert_eq!(0x16000073, rv32priv::sinval_vma(None, None));
+    assert_eq!(0x16050073, rv32priv::sinval_vma(Some(A0), None));
+    assert_eq!(0x16b50073, rv32priv::sinval_vma(Some(A0), Some(A1)));
+}
+
 #[test]
 fn test_sll() {
     test3("sll", rv32i::sll);
@@ -620,6 +1295,11 @@ fn test_srai() {
     test3_filter("srai", rv32i::srai, |_, _, shamt| shamt > 0 && shamt < 0x20);
 }
 
+#[test]
+fn test_sret() {
+    test0("sret", rv32priv::sret);
+}
+
 #[test]
 fn test_srl() {
     test3("srl", rv32i::srl);
@@ -655,6 +1335,11 @@ fn test_unimp() {
     test0("unimp", rv32i::unimp);
 }
 
+#[test]
+fn test_wfi() {
+    test0("wfi", rv32priv::wfi);
+}
+
 #[test]
 fn test_xor() {
     test3("xor", rv32i::xor);